@@ -0,0 +1,239 @@
+//! Activity feed.
+//!
+//! A lightweight in-memory event log that powers the dashboard timeline.
+//! Events are pushed from the frontend and from backend subsystems as they
+//! gain integration.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+/// Cap so an app left running for days doesn't grow without bound.
+const MAX_EVENTS: usize = 1000;
+
+static EVENTS: Mutex<Vec<ActivityEvent>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityEventType {
+    AgentStart,
+    AgentComplete,
+    Error,
+    SpecCreated,
+    SpecApproved,
+    PrOpened,
+    PrMerged,
+    IssueOpened,
+    IssueClosed,
+    Commit,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityEvent {
+    pub id: String,
+    pub event_type: ActivityEventType,
+    pub project: String,
+    pub message: String,
+    pub timestamp: String,
+}
+
+/// Record an event. Usable from anywhere in the backend.
+pub fn record_event(event_type: ActivityEventType, project: &str, message: &str) -> ActivityEvent {
+    let event = ActivityEvent {
+        id: Uuid::new_v4().to_string(),
+        event_type,
+        project: project.to_string(),
+        message: message.to_string(),
+        timestamp: Utc::now().to_rfc3339(),
+    };
+    let mut events = EVENTS.lock().unwrap();
+    events.push(event.clone());
+    if events.len() > MAX_EVENTS {
+        let excess = events.len() - MAX_EVENTS;
+        events.drain(0..excess);
+    }
+    event
+}
+
+/// Events for the timeline, newest first. All filters are optional and
+/// combine: project, event type, `since`/`until` RFC 3339 bounds, and a
+/// case-insensitive substring search over the message.
+#[tauri::command]
+pub fn get_activity_events(
+    project: Option<String>,
+    event_type: Option<ActivityEventType>,
+    since: Option<String>,
+    until: Option<String>,
+    search: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<ActivityEvent>, String> {
+    let search = search.map(|s| s.to_lowercase());
+    let events = EVENTS.lock().unwrap();
+    let mut filtered: Vec<ActivityEvent> = events
+        .iter()
+        .filter(|e| project.as_deref().is_none_or(|p| e.project == p))
+        .filter(|e| event_type.is_none_or(|t| e.event_type == t))
+        // RFC 3339 timestamps in UTC compare correctly as strings.
+        .filter(|e| since.as_deref().is_none_or(|s| e.timestamp.as_str() >= s))
+        .filter(|e| until.as_deref().is_none_or(|u| e.timestamp.as_str() <= u))
+        .filter(|e| {
+            search
+                .as_deref()
+                .is_none_or(|s| e.message.to_lowercase().contains(s))
+        })
+        .cloned()
+        .collect();
+    filtered.reverse();
+    filtered.truncate(limit.unwrap_or(100));
+    Ok(filtered)
+}
+
+/// Last-seen HEAD per tracked repo, so the poller only records new commits.
+static LAST_SEEN_HEAD: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+const COMMIT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// One pass over the tracked repos: record an event per commit that landed
+/// since the previous pass. First sight of a repo just baselines its HEAD so
+/// history isn't replayed into the feed.
+fn poll_commits(app: &AppHandle) {
+    let Ok(paths) = crate::commands::read_tracked_projects() else {
+        return;
+    };
+    let mut any_new = false;
+    for path in paths {
+        if !path.join(".git").exists() {
+            continue;
+        }
+        let Ok(head) = crate::git::run_git(&path, &["rev-parse", "HEAD"]) else {
+            continue;
+        };
+        let head = head.trim().to_string();
+        let key = path.display().to_string();
+
+        let previous = {
+            let mut seen = LAST_SEEN_HEAD.lock().unwrap();
+            let map = seen.get_or_insert_with(HashMap::new);
+            map.insert(key, head.clone())
+        };
+        let Some(previous) = previous else {
+            continue;
+        };
+        if previous == head {
+            continue;
+        }
+
+        let project = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let Ok(log) = crate::git::run_git(
+            &path,
+            &[
+                "log",
+                &format!("{}..{}", previous, head),
+                "--pretty=format:%H%x1f%an%x1f%aI%x1f%s",
+            ],
+        ) else {
+            continue;
+        };
+        // Oldest first, so the feed reads chronologically.
+        for commit in crate::git::parse_log(&log).into_iter().rev() {
+            record_event(
+                ActivityEventType::Commit,
+                &project,
+                &format!(
+                    "{} committed {}: {}",
+                    commit.author,
+                    &commit.hash[..commit.hash.len().min(8)],
+                    commit.message
+                ),
+            );
+            any_new = true;
+        }
+    }
+    if any_new {
+        let _ = app.emit("activity-updated", ());
+    }
+}
+
+/// Watch tracked repos for new commits and feed them into the activity log.
+pub fn start_commit_poller(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            poll_commits(&app);
+            tokio::time::sleep(COMMIT_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Quote a CSV field per RFC 4180.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render events as CSV with a header row, oldest first.
+pub fn events_to_csv(events: &[ActivityEvent]) -> String {
+    let mut out = String::from("id,eventType,project,message,timestamp\n");
+    for event in events {
+        let event_type = serde_json::to_value(event.event_type)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&event.id),
+            event_type,
+            csv_field(&event.project),
+            csv_field(&event.message),
+            csv_field(&event.timestamp),
+        ));
+    }
+    out
+}
+
+/// Export the activity history (optionally scoped to a project) to a JSON or
+/// CSV file at `path`. Returns the number of events written.
+#[tauri::command]
+pub fn export_activity_events(
+    format: String,
+    project: Option<String>,
+    path: String,
+) -> Result<usize, String> {
+    let events = EVENTS.lock().unwrap();
+    let mut selected: Vec<ActivityEvent> = events
+        .iter()
+        .filter(|e| project.as_deref().is_none_or(|p| e.project == p))
+        .cloned()
+        .collect();
+    drop(events);
+    selected.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let content = match format.as_str() {
+        "json" => serde_json::to_string_pretty(&selected).map_err(|e| e.to_string())?,
+        "csv" => events_to_csv(&selected),
+        other => return Err(format!("Unknown format \"{}\" (expected json or csv)", other)),
+    };
+    std::fs::write(&path, content).map_err(|e| e.to_string())?;
+    Ok(selected.len())
+}
+
+/// Push an event from the frontend.
+#[tauri::command]
+pub fn add_activity_event(
+    event_type: ActivityEventType,
+    project: String,
+    message: String,
+) -> Result<ActivityEvent, String> {
+    Ok(record_event(event_type, &project, &message))
+}
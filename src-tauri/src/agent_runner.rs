@@ -0,0 +1,380 @@
+//! Local agent process manager.
+//!
+//! Spawns Claude Code (or any configured CLI agent) as a local child process
+//! per issue: each agent gets a fresh clone of its project under the workdir
+//! from [`crate::agents::agent_workdir`], stdout/stderr mirrored into
+//! `~/.claude/telemetry/agent-<id>.log` (so the existing log streaming picks
+//! it up), and PID state tracked in an in-process registry. A scheduler
+//! enforces the configured global and per-project concurrency caps; starts
+//! beyond the caps queue in FIFO order and run as slots free up, so kicking
+//! off ten issues at once no longer blows through rate limits and budget.
+
+use std::collections::HashMap;
+use std::fs;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::activity::{self, ActivityEventType};
+use crate::agents;
+use crate::commands;
+use crate::settings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalAgentStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Killed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalAgent {
+    pub agent_id: String,
+    pub project: String,
+    pub project_path: String,
+    pub issue: Option<u64>,
+    pub command: String,
+    pub pid: Option<u32>,
+    pub status: LocalAgentStatus,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub exit_code: Option<i32>,
+}
+
+struct LocalAgentEntry {
+    info: LocalAgent,
+    child: Option<Arc<Mutex<std::process::Child>>>,
+}
+
+static AGENTS: Mutex<Option<HashMap<String, LocalAgentEntry>>> = Mutex::new(None);
+
+/// Agent ids waiting for a slot, oldest first.
+static QUEUE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Payload for `agent-queue-updated`, one entry per waiting agent.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QueuePosition {
+    agent_id: String,
+    position: usize,
+}
+
+/// The default agent invocation when no command is configured.
+fn default_command(issue: Option<u64>) -> String {
+    match issue {
+        Some(number) => format!(
+            "claude -p \"Work on GitHub issue #{}. Commit your changes when done.\"",
+            number
+        ),
+        None => "claude -p \"Follow the project instructions in CLAUDE.md.\"".to_string(),
+    }
+}
+
+/// Clone the project into the agent's private workdir so concurrent agents
+/// never trample each other's working copies.
+fn prepare_workdir(agent_id: &str, project_path: &str) -> Result<std::path::PathBuf, String> {
+    let workdir = agents::agent_workdir(agent_id)?;
+    if let Some(parent) = workdir.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let output = Command::new("git")
+        .args(["clone", project_path, &workdir.to_string_lossy()])
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(workdir)
+}
+
+fn emit_queue_positions(app: &AppHandle) {
+    let positions: Vec<QueuePosition> = QUEUE
+        .lock()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .map(|(i, agent_id)| QueuePosition {
+            agent_id: agent_id.clone(),
+            position: i + 1,
+        })
+        .collect();
+    let _ = app.emit("agent-queue-updated", positions);
+}
+
+/// Request a local agent for a project (optionally scoped to an issue). The
+/// agent starts immediately when the concurrency caps allow, otherwise it
+/// queues; either way its registry entry is returned and queue-position
+/// updates flow through `agent-queue-updated` events.
+#[tauri::command]
+pub fn start_local_agent(
+    app: AppHandle,
+    project_path: String,
+    issue: Option<u64>,
+    command: Option<String>,
+) -> Result<LocalAgent, String> {
+    let loaded = settings::load_settings()?;
+    if loaded.block_agents_over_budget {
+        let budget = crate::costs::budget_status()?;
+        if budget.exceeded {
+            return Err(format!(
+                "Monthly budget exceeded (${:.2} of ${:.2}); not starting new agents",
+                budget.spent_usd, budget.monthly_budget
+            ));
+        }
+    }
+
+    let agent_id = format!("local-{}", Uuid::new_v4());
+    let project = std::path::Path::new(&project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let info = LocalAgent {
+        agent_id: agent_id.clone(),
+        project,
+        project_path,
+        issue,
+        command: command.unwrap_or_else(|| default_command(issue)),
+        pid: None,
+        status: LocalAgentStatus::Queued,
+        started_at: Utc::now().to_rfc3339(),
+        finished_at: None,
+        exit_code: None,
+    };
+    AGENTS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(
+            agent_id.clone(),
+            LocalAgentEntry {
+                info: info.clone(),
+                child: None,
+            },
+        );
+    QUEUE.lock().unwrap().push(agent_id.clone());
+
+    dispatch_queue(&app);
+    emit_queue_positions(&app);
+    let _ = app.emit("agents-updated", ());
+
+    let agents = AGENTS.lock().unwrap();
+    Ok(agents
+        .as_ref()
+        .and_then(|map| map.get(&agent_id))
+        .map(|e| e.info.clone())
+        .unwrap_or(info))
+}
+
+/// Start queued agents while the concurrency caps have room.
+fn dispatch_queue(app: &AppHandle) {
+    let loaded = settings::load_settings().unwrap_or_default();
+    loop {
+        // Snapshot running counts, then take the first queued agent whose
+        // project still has capacity; later entries may proceed past a
+        // project that is at its cap.
+        let next = {
+            let queue = QUEUE.lock().unwrap();
+            let agents = AGENTS.lock().unwrap();
+            let mut global: u32 = 0;
+            let mut per_project: HashMap<String, u32> = HashMap::new();
+            for entry in agents.as_ref().map(|m| m.values()).into_iter().flatten() {
+                if entry.info.status == LocalAgentStatus::Running {
+                    global += 1;
+                    *per_project
+                        .entry(entry.info.project_path.clone())
+                        .or_default() += 1;
+                }
+            }
+            if global >= loaded.max_concurrent_agents {
+                break;
+            }
+            queue
+                .iter()
+                .find(|id| {
+                    agents
+                        .as_ref()
+                        .and_then(|m| m.get(*id))
+                        .is_some_and(|entry| {
+                            per_project
+                                .get(&entry.info.project_path)
+                                .copied()
+                                .unwrap_or(0)
+                                < loaded.max_agents_per_project
+                        })
+                })
+                .cloned()
+        };
+        let Some(agent_id) = next else { break };
+        QUEUE.lock().unwrap().retain(|id| id != &agent_id);
+        if let Err(e) = spawn_agent(app, &agent_id) {
+            mark_finished(app, &agent_id, LocalAgentStatus::Failed, None);
+            log::error!("Failed to start local agent {}: {}", agent_id, e);
+        }
+    }
+}
+
+/// Spawn the process for a queued agent and flip it to running.
+fn spawn_agent(app: &AppHandle, agent_id: &str) -> Result<(), String> {
+    let info = {
+        let agents = AGENTS.lock().unwrap();
+        agents
+            .as_ref()
+            .and_then(|map| map.get(agent_id))
+            .map(|e| e.info.clone())
+            .ok_or_else(|| format!("Unknown local agent: {}", agent_id))?
+    };
+    let workdir = prepare_workdir(agent_id, &info.project_path)?;
+
+    let telemetry = commands::telemetry_dir()?;
+    fs::create_dir_all(&telemetry).map_err(|e| e.to_string())?;
+    let log_path = telemetry.join(format!("agent-{}.log", agent_id));
+    let log = fs::File::create(&log_path).map_err(|e| e.to_string())?;
+    let log_err = log.try_clone().map_err(|e| e.to_string())?;
+
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/C", &info.command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", &info.command]);
+        c
+    };
+    let child = cmd
+        .current_dir(&workdir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(log))
+        .stderr(Stdio::from(log_err))
+        .spawn()
+        .map_err(|e| format!("Failed to spawn agent: {}", e))?;
+
+    let pid = child.id();
+    let child = Arc::new(Mutex::new(child));
+    {
+        let mut agents = AGENTS.lock().unwrap();
+        if let Some(entry) = agents.as_mut().and_then(|map| map.get_mut(agent_id)) {
+            entry.info.status = LocalAgentStatus::Running;
+            entry.info.pid = Some(pid);
+            entry.info.started_at = Utc::now().to_rfc3339();
+            entry.child = Some(child.clone());
+        }
+    }
+
+    activity::record_event(
+        ActivityEventType::AgentStart,
+        &info.project,
+        &format!("Local agent {} started", agent_id),
+    );
+    let _ = app.emit("agents-updated", ());
+
+    watch_agent(app.clone(), agent_id.to_string(), child);
+    Ok(())
+}
+
+/// Wait for the child on a background thread and record its outcome.
+fn watch_agent(app: AppHandle, agent_id: String, child: Arc<Mutex<std::process::Child>>) {
+    std::thread::spawn(move || {
+        let exit_code = loop {
+            match child.lock().unwrap().try_wait() {
+                Ok(Some(status)) => break status.code(),
+                Ok(None) => {}
+                Err(_) => break None,
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        };
+        let status = if exit_code == Some(0) {
+            LocalAgentStatus::Completed
+        } else {
+            LocalAgentStatus::Failed
+        };
+        mark_finished(&app, &agent_id, status, exit_code);
+    });
+}
+
+/// Record an agent's terminal state and hand its slot to the queue.
+fn mark_finished(
+    app: &AppHandle,
+    agent_id: &str,
+    status: LocalAgentStatus,
+    exit_code: Option<i32>,
+) {
+    {
+        let mut agents = AGENTS.lock().unwrap();
+        let Some(entry) = agents.as_mut().and_then(|map| map.get_mut(agent_id)) else {
+            return;
+        };
+        // A kill already set its status; don't overwrite it.
+        if entry.info.status == LocalAgentStatus::Running
+            || entry.info.status == LocalAgentStatus::Queued
+        {
+            entry.info.status = status;
+        }
+        entry.info.exit_code = exit_code;
+        entry.info.finished_at = Some(Utc::now().to_rfc3339());
+        let (event_type, verb) = match entry.info.status {
+            LocalAgentStatus::Completed => (ActivityEventType::AgentComplete, "completed"),
+            LocalAgentStatus::Killed => (ActivityEventType::AgentComplete, "was killed"),
+            _ => (ActivityEventType::Error, "failed"),
+        };
+        activity::record_event(
+            event_type,
+            &entry.info.project,
+            &format!("Local agent {} {}", agent_id, verb),
+        );
+    }
+    let _ = app.emit("agents-updated", ());
+    dispatch_queue(app);
+    emit_queue_positions(app);
+}
+
+/// Kill a running local agent or drop a queued one. Finished agents are not
+/// an error; killing them is a no-op.
+#[tauri::command]
+pub fn kill_local_agent(app: AppHandle, agent_id: String) -> Result<(), String> {
+    let child = {
+        let mut agents = AGENTS.lock().unwrap();
+        let Some(entry) = agents.as_mut().and_then(|map| map.get_mut(&agent_id)) else {
+            return Err(format!("Unknown local agent: {}", agent_id));
+        };
+        match entry.info.status {
+            LocalAgentStatus::Queued => {
+                entry.info.status = LocalAgentStatus::Killed;
+                entry.info.finished_at = Some(Utc::now().to_rfc3339());
+                None
+            }
+            LocalAgentStatus::Running => {
+                entry.info.status = LocalAgentStatus::Killed;
+                entry.child.clone()
+            }
+            _ => return Ok(()),
+        }
+    };
+    QUEUE.lock().unwrap().retain(|id| id != &agent_id);
+    if let Some(child) = child {
+        child.lock().unwrap().kill().map_err(|e| e.to_string())?;
+    }
+    emit_queue_positions(&app);
+    let _ = app.emit("agents-updated", ());
+    Ok(())
+}
+
+/// All local agents this session, newest first.
+#[tauri::command]
+pub fn list_local_agents() -> Result<Vec<LocalAgent>, String> {
+    let agents = AGENTS.lock().unwrap();
+    let mut list: Vec<LocalAgent> = agents
+        .as_ref()
+        .map(|map| map.values().map(|e| e.info.clone()).collect())
+        .unwrap_or_default();
+    list.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(list)
+}
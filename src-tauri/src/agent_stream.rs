@@ -0,0 +1,164 @@
+//! Live agent log streaming.
+//!
+//! Workflow logs are mirrored into `~/.claude/telemetry/agent-<id>.log` by
+//! the runner. Streaming a log tails that file with a notify debouncer (the
+//! same mechanism `watcher.rs` uses) and emits incremental
+//! `agent-stream-update` events only when data actually arrives, so a dozen
+//! concurrent streams cost nothing while their logs are idle. Active streams
+//! are tracked in a registry so they can be stopped, queried, and
+//! deduplicated.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::commands;
+use crate::events;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentStreamUpdate {
+    pub agent_id: String,
+    pub chunk: String,
+}
+
+/// Per-stream state shared between the registry and the tail thread.
+struct AgentStreamState {
+    active: Arc<AtomicBool>,
+}
+
+static STREAMS: Mutex<Option<HashMap<String, AgentStreamState>>> = Mutex::new(None);
+
+/// How often the tail thread wakes to check whether it was stopped, absent
+/// any file activity.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Read and emit whatever the log has appended past `last_len`.
+fn emit_new_bytes(app: &AppHandle, agent_id: &str, log_path: &std::path::Path, last_len: &mut u64) {
+    let Ok(content) = std::fs::read_to_string(log_path) else {
+        return;
+    };
+    let len = content.len() as u64;
+    if len > *last_len {
+        let chunk = content[*last_len as usize..].to_string();
+        *last_len = len;
+        events::emit_scoped(
+            app,
+            "agent-stream-update",
+            AgentStreamUpdate {
+                agent_id: agent_id.to_string(),
+                chunk,
+            },
+        );
+    } else if len < *last_len {
+        // The runner truncated and restarted the log.
+        *last_len = 0;
+    }
+}
+
+/// Start tailing an agent's log file and emit only the newly appended bytes
+/// whenever the file changes. A second call for the same agent is a no-op
+/// while its stream is running.
+#[tauri::command]
+pub fn start_agent_stream(app: AppHandle, agent_id: String) -> Result<(), String> {
+    let telemetry = commands::telemetry_dir()?;
+    let log_path = telemetry.join(format!("agent-{}.log", agent_id));
+
+    let active = Arc::new(AtomicBool::new(true));
+    {
+        let mut streams = STREAMS.lock().unwrap();
+        let map = streams.get_or_insert_with(HashMap::new);
+        if map.contains_key(&agent_id) {
+            return Ok(());
+        }
+        map.insert(
+            agent_id.clone(),
+            AgentStreamState {
+                active: active.clone(),
+            },
+        );
+    }
+
+    std::thread::spawn(move || {
+        // Watch the telemetry directory rather than the log itself: the file
+        // may not exist yet when streaming starts.
+        let (tx, rx) = mpsc::channel::<()>();
+        let watched = log_path.clone();
+        let debouncer = new_debouncer(Duration::from_millis(250), move |events| {
+            if let Ok(events) = events {
+                if events.iter().any(|e| e.path == watched) {
+                    let _ = tx.send(());
+                }
+            }
+        });
+        let mut debouncer = match debouncer {
+            Ok(d) => d,
+            Err(e) => {
+                log::error!("Agent stream watcher failed for {}: {}", agent_id, e);
+                remove_stream(&agent_id);
+                return;
+            }
+        };
+        if let Err(e) = debouncer
+            .watcher()
+            .watch(&telemetry, RecursiveMode::NonRecursive)
+        {
+            log::error!("Agent stream watcher failed for {}: {}", agent_id, e);
+            remove_stream(&agent_id);
+            return;
+        }
+
+        // Catch up on whatever the log already holds before waiting for
+        // change notifications.
+        let mut last_len: u64 = 0;
+        emit_new_bytes(&app, &agent_id, &log_path, &mut last_len);
+
+        while active.load(Ordering::Relaxed) {
+            match rx.recv_timeout(STOP_CHECK_INTERVAL) {
+                Ok(()) => emit_new_bytes(&app, &agent_id, &log_path, &mut last_len),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        // The thread owns removal, so a stream that winds down on its own
+        // also leaves the registry.
+        remove_stream(&agent_id);
+    });
+
+    Ok(())
+}
+
+fn remove_stream(agent_id: &str) {
+    if let Some(map) = STREAMS.lock().unwrap().as_mut() {
+        map.remove(agent_id);
+    }
+}
+
+/// Stop streaming an agent's log. Unknown ids are not an error; the stream
+/// may already have wound down.
+#[tauri::command]
+pub fn stop_agent_stream(agent_id: String) -> Result<(), String> {
+    if let Some(map) = STREAMS.lock().unwrap().as_mut() {
+        if let Some(state) = map.get(&agent_id) {
+            state.active.store(false, Ordering::Relaxed);
+        }
+    }
+    Ok(())
+}
+
+/// Ids of currently streaming agents.
+#[tauri::command]
+pub fn get_active_streams() -> Result<Vec<String>, String> {
+    Ok(STREAMS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|map| map.keys().cloned().collect())
+        .unwrap_or_default())
+}
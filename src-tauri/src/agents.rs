@@ -0,0 +1,403 @@
+//! Agent monitoring.
+//!
+//! Agents are GitHub Actions workflow runs dispatched against issues labelled
+//! `ai-feature`. Sentra observes them through the gh CLI per tracked project.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::activity::{self, ActivityEventType};
+use crate::commands;
+use crate::time_tracking;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Agent {
+    pub id: String,
+    pub project: String,
+    pub workflow_name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub branch: String,
+    pub started_at: String,
+    pub elapsed_seconds: i64,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhRun {
+    #[serde(rename = "databaseId")]
+    database_id: u64,
+    #[serde(rename = "workflowName")]
+    workflow_name: String,
+    status: String,
+    conclusion: Option<String>,
+    #[serde(rename = "headBranch")]
+    head_branch: String,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<String>,
+    url: String,
+}
+
+fn list_runs(project_path: &Path, extra_args: &[&str]) -> Result<Vec<GhRun>, String> {
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "run",
+        "list",
+        "--json",
+        "databaseId,workflowName,status,conclusion,headBranch,createdAt,updatedAt,url",
+    ])
+    .args(extra_args)
+    .current_dir(project_path);
+    let output = cmd.output().map_err(|e| format!("Failed to run gh: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("Failed to parse gh output: {}", e))
+}
+
+fn convert_run(run: GhRun, project: &str) -> Agent {
+    let elapsed_seconds = DateTime::parse_from_rfc3339(&run.created_at)
+        .map(|start| (Utc::now() - start.with_timezone(&Utc)).num_seconds())
+        .unwrap_or(0);
+    Agent {
+        id: run.database_id.to_string(),
+        project: project.to_string(),
+        workflow_name: run.workflow_name,
+        status: run.status,
+        conclusion: run.conclusion,
+        branch: run.head_branch,
+        started_at: run.created_at,
+        elapsed_seconds,
+        url: run.url,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStep {
+    pub number: u32,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub duration_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowJob {
+    pub id: u64,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub started_at: Option<String>,
+    pub duration_seconds: i64,
+    pub url: String,
+    pub steps: Vec<WorkflowStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowRunDetails {
+    pub run_id: String,
+    pub jobs: Vec<WorkflowJob>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhStep {
+    number: u32,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    #[serde(rename = "startedAt")]
+    started_at: Option<String>,
+    #[serde(rename = "completedAt")]
+    completed_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhJob {
+    #[serde(rename = "databaseId")]
+    database_id: u64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    #[serde(rename = "startedAt")]
+    started_at: Option<String>,
+    #[serde(rename = "completedAt")]
+    completed_at: Option<String>,
+    url: String,
+    #[serde(default)]
+    steps: Vec<GhStep>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhRunView {
+    jobs: Vec<GhJob>,
+}
+
+/// Seconds between two RFC 3339 timestamps. A missing end means the work is
+/// still running, so the clock keeps counting from now.
+pub fn duration_seconds(started: Option<&str>, completed: Option<&str>) -> i64 {
+    let Some(start) = started.and_then(|s| DateTime::parse_from_rfc3339(s).ok()) else {
+        return 0;
+    };
+    let end = completed
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now);
+    (end - start.with_timezone(&Utc)).num_seconds().max(0)
+}
+
+/// Job and step breakdown for one workflow run, so the agent detail view can
+/// show which step is running or failed rather than a single opaque status.
+#[tauri::command]
+pub fn get_workflow_run_details(
+    project_path: String,
+    run_id: String,
+) -> Result<WorkflowRunDetails, String> {
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args(["run", "view", &run_id, "--json", "jobs"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    let view: GhRunView = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh output: {}", e))?;
+
+    let jobs = view
+        .jobs
+        .into_iter()
+        .map(|job| WorkflowJob {
+            id: job.database_id,
+            name: job.name,
+            status: job.status,
+            conclusion: job.conclusion,
+            duration_seconds: duration_seconds(job.started_at.as_deref(), job.completed_at.as_deref()),
+            started_at: job.started_at,
+            url: job.url,
+            steps: job
+                .steps
+                .into_iter()
+                .map(|step| WorkflowStep {
+                    number: step.number,
+                    name: step.name,
+                    status: step.status,
+                    conclusion: step.conclusion,
+                    duration_seconds: duration_seconds(
+                        step.started_at.as_deref(),
+                        step.completed_at.as_deref(),
+                    ),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(WorkflowRunDetails { run_id, jobs })
+}
+
+/// In-progress workflow runs across every tracked project with a git remote.
+#[tauri::command]
+pub fn get_active_agents() -> Result<Vec<Agent>, String> {
+    let mut agents = Vec::new();
+    for path in commands::read_tracked_projects()? {
+        if !path.join(".git").exists() {
+            continue;
+        }
+        let project = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // Projects without a GitHub remote just error out of gh; skip them.
+        let runs = match list_runs(&path, &["--status", "in_progress"]) {
+            Ok(runs) => runs,
+            Err(_) => continue,
+        };
+        agents.extend(runs.into_iter().map(|r| convert_run(r, &project)));
+    }
+    Ok(agents)
+}
+
+/// Previous `get_active_agents` snapshot, keyed by run id, so transitions
+/// (started, finished) can be detected by diffing.
+static LAST_ACTIVE: Mutex<Option<HashMap<String, Agent>>> = Mutex::new(None);
+
+const AGENT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The conclusion of a finished run, looked up in its project directory.
+fn run_conclusion(project: &str, run_id: &str) -> Option<String> {
+    let path = commands::read_tracked_projects()
+        .ok()?
+        .into_iter()
+        .find(|p| p.file_name().map(|n| n.to_string_lossy() == *project) == Some(true))?;
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args(["run", "view", run_id, "--json", "conclusion"])
+        .current_dir(&path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    serde_json::from_slice::<serde_json::Value>(&output.stdout)
+        .ok()?
+        .get("conclusion")?
+        .as_str()
+        .map(String::from)
+}
+
+/// One poll pass: diff the active-agent snapshot against the previous one
+/// and record start/finish transitions in the activity feed. The first pass
+/// only baselines, so a restart doesn't replay running agents as "started".
+fn poll_agent_transitions(app: &AppHandle) {
+    let Ok(active) = get_active_agents() else {
+        return;
+    };
+    let current: HashMap<String, Agent> =
+        active.into_iter().map(|a| (a.id.clone(), a)).collect();
+
+    let previous = {
+        let mut last = LAST_ACTIVE.lock().unwrap();
+        last.replace(current.clone())
+    };
+    let Some(previous) = previous else {
+        return;
+    };
+
+    let mut any = false;
+    for (id, agent) in &current {
+        if !previous.contains_key(id) {
+            activity::record_event(
+                ActivityEventType::AgentStart,
+                &agent.project,
+                &format!("Agent started: {} on {}", agent.workflow_name, agent.branch),
+            );
+            any = true;
+        }
+    }
+    for (id, agent) in &previous {
+        if current.contains_key(id) {
+            continue;
+        }
+        let conclusion = run_conclusion(&agent.project, id);
+        let (event_type, verb) = match conclusion.as_deref() {
+            Some("success") => (ActivityEventType::AgentComplete, "completed"),
+            Some(_) => (ActivityEventType::Error, "failed"),
+            None => (ActivityEventType::AgentComplete, "finished"),
+        };
+        activity::record_event(
+            event_type,
+            &agent.project,
+            &format!("Agent {}: {} on {}", verb, agent.workflow_name, agent.branch),
+        );
+        any = true;
+    }
+    if any {
+        let _ = app.emit("activity-updated", ());
+    }
+}
+
+/// Watch workflow runs and feed lifecycle transitions into the activity log.
+pub fn start_agent_poller(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            poll_agent_transitions(&app);
+            tokio::time::sleep(AGENT_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Where a locally-run agent's checkout lives.
+pub fn agent_workdir(agent_id: &str) -> Result<std::path::PathBuf, String> {
+    Ok(crate::settings::sentra_dir()?.join("agents").join(agent_id))
+}
+
+/// Everything a locally-run agent changed in its working copy — staged,
+/// unstaged, and untracked — as a structured diff, for sanity-checking
+/// before the agent pushes or opens a PR.
+#[tauri::command]
+pub fn get_agent_workdir_diff(agent_id: String) -> Result<crate::git::GitDiff, String> {
+    let dir = agent_workdir(&agent_id)?;
+    if !dir.join(".git").exists() {
+        return Err(format!("No local worktree for agent {}", agent_id));
+    }
+
+    // Diff against HEAD so staged and unstaged changes both show up.
+    let patch = crate::git::run_git(&dir, &["diff", "HEAD"])?;
+    let mut files: Vec<crate::git::DiffFile> = crate::git::run_git(&dir, &["diff", "HEAD", "--name-only"])?
+        .lines()
+        .map(|l| crate::git::DiffFile {
+            path: l.to_string(),
+            additions: 0,
+            deletions: 0,
+            renamed_from: None,
+            binary: false,
+            patch: String::new(),
+        })
+        .collect();
+    for line in crate::git::run_git(&dir, &["ls-files", "--others", "--exclude-standard"])?.lines() {
+        files.push(crate::git::DiffFile {
+            path: line.to_string(),
+            additions: 0,
+            deletions: 0,
+            renamed_from: None,
+            binary: false,
+            patch: String::new(),
+        });
+    }
+
+    Ok(crate::git::GitDiff { files, patch })
+}
+
+/// Recently completed workflow runs across tracked projects.
+#[tauri::command]
+pub fn get_agent_history(limit: Option<u32>) -> Result<Vec<Agent>, String> {
+    let limit = limit.unwrap_or(20).to_string();
+    let mut agents = Vec::new();
+    for path in commands::read_tracked_projects()? {
+        if !path.join(".git").exists() {
+            continue;
+        }
+        let project = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let runs = match list_runs(&path, &["--status", "completed", "--limit", &limit]) {
+            Ok(runs) => runs,
+            Err(_) => continue,
+        };
+        for run in runs {
+            // The ledger wants the run's actual duration, not elapsed time
+            // since creation — a run first listed days after it finished
+            // would otherwise record days of phantom agent time.
+            let run_duration =
+                duration_seconds(Some(&run.created_at), run.updated_at.as_deref());
+            let agent = convert_run(run, &project);
+            // Completed runs feed the time-tracking ledger; record() dedups
+            // by run id so re-listing doesn't double-count.
+            let _ = time_tracking::record(
+                &project,
+                time_tracking::TimeEntryKind::Agent,
+                &agent.id,
+                run_duration,
+            );
+            agents.push(agent);
+        }
+    }
+    agents.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    agents.truncate(limit.parse().unwrap_or(20));
+    Ok(agents)
+}
@@ -0,0 +1,733 @@
+//! Architect AI integration.
+//!
+//! The architect is a conversational layer over an LLM provider (Anthropic
+//! by default, see [`crate::llm`]) that turns voice or typed discussions
+//! into specs. Audio capture is transcribed via OpenAI Whisper.
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::llm;
+use crate::rate_limit;
+use crate::settings;
+
+const ARCHITECT_MODEL: &str = "claude-sonnet-4-5-20250929";
+const MAX_TOKENS: u32 = 4096;
+
+/// Ceiling on tool rounds per chat turn so a confused model can't loop
+/// creating issues forever.
+const MAX_TOOL_ROUNDS: usize = 5;
+
+/// Context window of the architect model, in tokens.
+const CONTEXT_WINDOW_TOKENS: usize = 200_000;
+
+/// Headroom left unused so tool descriptors, stop sequences, and estimation
+/// error don't push a request over the real limit.
+const CONTEXT_MARGIN_TOKENS: usize = 2_000;
+
+/// Rough token count: ~4 characters per token, the same heuristic the
+/// project-context budget uses.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+fn message_tokens(message: &ChatMessage) -> usize {
+    // Small per-message overhead for role framing.
+    estimate_tokens(&message.content) + 4
+}
+
+/// Fit a conversation into the model's context window. The newest messages
+/// are kept verbatim; anything older is collapsed into a single extractive
+/// summary message so the model keeps some memory of how the conversation
+/// started instead of the API rejecting the request with a 400.
+pub fn trim_to_context_window(system: &str, messages: &[ChatMessage]) -> Vec<ChatMessage> {
+    let budget = CONTEXT_WINDOW_TOKENS
+        .saturating_sub(MAX_TOKENS as usize)
+        .saturating_sub(estimate_tokens(system))
+        .saturating_sub(CONTEXT_MARGIN_TOKENS);
+
+    let total: usize = messages.iter().map(message_tokens).sum();
+    if total <= budget {
+        return messages.to_vec();
+    }
+
+    // Walk backwards from the newest message until the budget is spent,
+    // reserving a slice of it for the summary of what gets dropped.
+    let keep_budget = budget.saturating_sub(budget / 10);
+    let mut used = 0;
+    let mut keep_from = messages.len();
+    while keep_from > 0 && used + message_tokens(&messages[keep_from - 1]) <= keep_budget {
+        used += message_tokens(&messages[keep_from - 1]);
+        keep_from -= 1;
+    }
+
+    let mut summary = String::from("Summary of earlier discussion (trimmed to fit context):\n");
+    for message in &messages[..keep_from] {
+        let first_line = message.content.lines().next().unwrap_or_default();
+        let excerpt: String = first_line.chars().take(120).collect();
+        summary.push_str(&format!("- {}: {}\n", message.role, excerpt));
+        if estimate_tokens(&summary) > budget - keep_budget {
+            summary.push_str("- ...\n");
+            break;
+        }
+    }
+
+    let mut trimmed = vec![ChatMessage {
+        role: "user".to_string(),
+        content: summary,
+    }];
+    trimmed.extend_from_slice(&messages[keep_from..]);
+    trimmed
+}
+
+const SYSTEM_PROMPT: &str = "You are the Sentra architect, a senior software \
+architect who helps developers turn ideas into precise, implementable \
+specifications. Ask clarifying questions, propose concrete designs, and when \
+the user is satisfied, produce a complete markdown spec with a problem \
+statement, acceptance criteria, and implementation notes.";
+
+/// Resolve the architect persona for a project: `.sentra/architect.md` when
+/// present, otherwise the global override in settings, otherwise the
+/// built-in prompt. `{project}` and `{stack}` placeholders are substituted.
+fn system_prompt_for(loaded: &settings::Settings, project: &str) -> String {
+    let root = crate::commands::resolve_project_path(project).ok();
+    let base = root
+        .as_ref()
+        .and_then(|root| std::fs::read_to_string(root.join(".sentra").join("architect.md")).ok())
+        .map(|content| content.trim().to_string())
+        .filter(|content| !content.is_empty())
+        .or_else(|| {
+            Some(loaded.architect_system_prompt.clone()).filter(|prompt| !prompt.is_empty())
+        })
+        .unwrap_or_else(|| SYSTEM_PROMPT.to_string());
+
+    let stack = root
+        .as_ref()
+        .and_then(|root| crate::commands::project_config_value(root, "stack"))
+        .unwrap_or_else(|| "unknown".to_string());
+    let variables = std::collections::HashMap::from([
+        ("project".to_string(), project.to_string()),
+        ("stack".to_string(), stack),
+    ]);
+    crate::notifications::render_template(&base, &variables)
+}
+
+/// Token budget for the project context block injected into the system
+/// prompt. Kept small relative to the window so the conversation itself has
+/// room.
+const CONTEXT_BLOCK_TOKENS: usize = 8_000;
+
+/// Assemble the project's context (memory files, stack, recent activity)
+/// for injection into the system prompt, already trimmed to budget by
+/// `get_project_context`.
+fn context_block_for(project: &str) -> Option<String> {
+    let path = crate::commands::resolve_project_path(project)
+        .ok()?
+        .display()
+        .to_string();
+    let context = crate::commands::get_project_context(path, Some(CONTEXT_BLOCK_TOKENS)).ok()?;
+    if context.text.is_empty() {
+        return None;
+    }
+    Some(format!("Project context:\n\n{}", context.text))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// One stored architect conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Conversation {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub messages: Vec<ChatMessage>,
+}
+
+/// Listing entry; the messages stay on disk until the conversation opens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationSummary {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub message_count: usize,
+}
+
+fn conversations_dir(project_path: &str) -> std::path::PathBuf {
+    std::path::Path::new(project_path)
+        .join(".sentra")
+        .join("conversations")
+}
+
+fn conversation_file(project_path: &str, id: &str) -> Result<std::path::PathBuf, String> {
+    // Ids become file names; reject anything that could escape the dir.
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!("Invalid conversation id: {:?}", id));
+    }
+    Ok(conversations_dir(project_path).join(format!("{}.json", id)))
+}
+
+fn load_conversation(project_path: &str, id: &str) -> Result<Conversation, String> {
+    let path = conversation_file(project_path, id)?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|_| format!("Conversation not found: {}", id))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid conversation file: {}", e))
+}
+
+fn save_conversation(project_path: &str, conversation: &Conversation) -> Result<(), String> {
+    let dir = conversations_dir(project_path);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(conversation).map_err(|e| e.to_string())?;
+    std::fs::write(conversation_file(project_path, &conversation.id)?, json)
+        .map_err(|e| e.to_string())
+}
+
+/// Conversations of a project, newest activity first.
+#[tauri::command]
+pub fn list_conversations(project_path: String) -> Result<Vec<ConversationSummary>, String> {
+    let mut summaries = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(conversations_dir(&project_path)) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let Ok(content) = std::fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            let Ok(conversation) = serde_json::from_str::<Conversation>(&content) else {
+                continue;
+            };
+            summaries.push(ConversationSummary {
+                id: conversation.id,
+                title: conversation.title,
+                created_at: conversation.created_at,
+                updated_at: conversation.updated_at,
+                message_count: conversation.messages.len(),
+            });
+        }
+    }
+    summaries.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(summaries)
+}
+
+#[tauri::command]
+pub fn get_conversation(project_path: String, conversation_id: String) -> Result<Conversation, String> {
+    load_conversation(&project_path, &conversation_id)
+}
+
+#[tauri::command]
+pub fn delete_conversation(project_path: String, conversation_id: String) -> Result<(), String> {
+    let path = conversation_file(&project_path, &conversation_id)?;
+    std::fs::remove_file(path).map_err(|e| e.to_string())
+}
+
+/// Title a conversation after its first user message.
+fn conversation_title(messages: &[ChatMessage]) -> String {
+    messages
+        .iter()
+        .find(|m| m.role == "user")
+        .map(|m| {
+            m.content
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(80)
+                .collect()
+        })
+        .filter(|t: &String| !t.is_empty())
+        .unwrap_or_else(|| "Untitled conversation".to_string())
+}
+
+/// Send a conversation to the architect and return its reply.
+///
+/// On the Anthropic provider the architect gets tools (save a spec, open a
+/// GitHub issue, read project context and git history) and may run several
+/// tool rounds before answering. Other providers get a plain chat turn.
+///
+/// With a `conversation_id` the turn resumes that stored conversation: its
+/// history is prepended and the new messages plus reply are persisted under
+/// `.sentra/conversations/`.
+#[tauri::command]
+pub async fn chat_with_architect(
+    project: String,
+    messages: Vec<ChatMessage>,
+    conversation_id: Option<String>,
+) -> Result<String, String> {
+    let loaded = settings::load_settings()?;
+    let provider = llm::provider_for_project(&loaded, &project);
+
+    // Resume the stored history when a conversation id is given. A fresh id
+    // starts a new conversation under that id.
+    let mut stored: Option<Conversation> = None;
+    let mut messages = messages;
+    if let Some(id) = &conversation_id {
+        let project_path = crate::commands::resolve_project_path(&project)?
+            .display()
+            .to_string();
+        let conversation = match load_conversation(&project_path, id) {
+            Ok(existing) => existing,
+            Err(_) => Conversation {
+                id: id.clone(),
+                title: conversation_title(&messages),
+                created_at: chrono::Utc::now().to_rfc3339(),
+                updated_at: chrono::Utc::now().to_rfc3339(),
+                messages: Vec::new(),
+            },
+        };
+        let mut all = conversation.messages.clone();
+        all.extend(messages);
+        messages = all;
+        stored = Some(conversation);
+    }
+
+    let mut system = format!(
+        "{}\n\nThe current project is \"{}\".",
+        system_prompt_for(&loaded, &project),
+        project
+    );
+    if let Some(context) = context_block_for(&project) {
+        system.push_str("\n\n");
+        system.push_str(&context);
+    }
+    // The request is trimmed to the context window; the stored history keeps
+    // every message.
+    let trimmed = trim_to_context_window(&system, &messages);
+
+    let started = std::time::Instant::now();
+    let reply = if provider.name() == "anthropic" {
+        chat_with_tools(&loaded.anthropic_api_key, &project, &system, &trimmed).await?
+    } else {
+        provider
+            .chat(
+                &provider.architect_model(),
+                MAX_TOKENS,
+                &system,
+                &trimmed,
+                Some(&project),
+            )
+            .await?
+    };
+
+    if let Some(mut conversation) = stored {
+        let project_path = crate::commands::resolve_project_path(&project)?
+            .display()
+            .to_string();
+        conversation.messages = messages;
+        conversation.messages.push(ChatMessage {
+            role: "assistant".to_string(),
+            content: reply.clone(),
+        });
+        conversation.updated_at = chrono::Utc::now().to_rfc3339();
+        save_conversation(&project_path, &conversation)?;
+    }
+
+    let _ = crate::time_tracking::record(
+        &project,
+        crate::time_tracking::TimeEntryKind::Architect,
+        &format!("architect-{}", uuid::Uuid::new_v4()),
+        started.elapsed().as_secs() as i64,
+    );
+
+    Ok(reply)
+}
+
+/// Tool-use loop against the Anthropic messages API: send the conversation
+/// with tool descriptors, execute any requested tools, feed results back,
+/// repeat until the model answers in text.
+async fn chat_with_tools(
+    api_key: &str,
+    project: &str,
+    system: &str,
+    messages: &[ChatMessage],
+) -> Result<String, String> {
+    let mut convo: Vec<serde_json::Value> =
+        messages.iter().map(|m| serde_json::json!(m)).collect();
+
+    for _ in 0..MAX_TOOL_ROUNDS {
+        let response = llm::anthropic_messages(
+            api_key,
+            serde_json::json!({
+                "model": ARCHITECT_MODEL,
+                "max_tokens": MAX_TOKENS,
+                "system": system,
+                "messages": convo,
+                "tools": tool_descriptors(),
+            }),
+        )
+        .await?;
+
+        let _ = crate::usage::record(
+            "anthropic",
+            ARCHITECT_MODEL,
+            crate::usage::UsageKind::Architect,
+            response
+                .pointer("/usage/input_tokens")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0),
+            response
+                .pointer("/usage/output_tokens")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0),
+            Some(project),
+        );
+
+        let content = response.get("content").cloned().unwrap_or_default();
+        if response.get("stop_reason").and_then(|s| s.as_str()) != Some("tool_use") {
+            return Ok(collect_text(&content));
+        }
+
+        // Echo the assistant turn, then answer every tool_use block.
+        let mut results = Vec::new();
+        for block in content.as_array().into_iter().flatten() {
+            if block.get("type").and_then(|t| t.as_str()) != Some("tool_use") {
+                continue;
+            }
+            let id = block.get("id").and_then(|i| i.as_str()).unwrap_or_default();
+            let name = block
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or_default();
+            let input = block.get("input").cloned().unwrap_or_default();
+            let (text, is_error) = match run_tool(project, name, &input).await {
+                Ok(text) => (text, false),
+                Err(message) => (message, true),
+            };
+            results.push(serde_json::json!({
+                "type": "tool_result",
+                "tool_use_id": id,
+                "content": text,
+                "is_error": is_error,
+            }));
+        }
+        convo.push(serde_json::json!({ "role": "assistant", "content": content }));
+        convo.push(serde_json::json!({ "role": "user", "content": results }));
+    }
+
+    Err("Architect exceeded the tool-use round limit".to_string())
+}
+
+fn collect_text(content: &serde_json::Value) -> String {
+    content
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|block| block.get("text").and_then(|t| t.as_str()))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn tool_descriptors() -> serde_json::Value {
+    serde_json::json!([
+        {
+            "name": "save_spec",
+            "description": "Save (or update) a markdown spec in the project's .sentra/specs directory.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "specId": { "type": "string", "description": "Existing spec id to update; omit to create" },
+                    "title": { "type": "string" },
+                    "content": { "type": "string", "description": "Full markdown body" }
+                },
+                "required": ["title", "content"]
+            }
+        },
+        {
+            "name": "create_github_issue",
+            "description": "Open a GitHub issue on the project's repository.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string" },
+                    "body": { "type": "string" },
+                    "labels": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["title", "body"]
+            }
+        },
+        {
+            "name": "get_project_context",
+            "description": "Read the project's assembled context (memory, stack, recent activity).",
+            "input_schema": { "type": "object", "properties": {} }
+        },
+        {
+            "name": "get_git_log",
+            "description": "Recent commits on the project's current branch.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "integer", "description": "Max commits, default 20" }
+                }
+            }
+        }
+    ])
+}
+
+/// Execute one architect tool against the existing commands.
+async fn run_tool(project: &str, name: &str, input: &serde_json::Value) -> Result<String, String> {
+    let path = crate::commands::resolve_project_path(project)?
+        .display()
+        .to_string();
+    let str_arg = |key: &str| -> Result<String, String> {
+        input
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| format!("Missing {} argument", key))
+    };
+
+    match name {
+        "save_spec" => {
+            let spec_id = input
+                .get("specId")
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+            let saved =
+                crate::specs::save_spec(path, spec_id, str_arg("title")?, str_arg("content")?)?;
+            serde_json::to_string(&saved).map_err(|e| e.to_string())
+        }
+        "create_github_issue" => {
+            let labels = input.get("labels").and_then(|v| v.as_array()).map(|items| {
+                items
+                    .iter()
+                    .filter_map(|l| l.as_str())
+                    .map(str::to_string)
+                    .collect()
+            });
+            crate::commands::create_github_issue(path, str_arg("title")?, str_arg("body")?, labels)
+                .await
+        }
+        "get_project_context" => {
+            let context = crate::commands::get_project_context(path, Some(4000))?;
+            serde_json::to_string(&context).map_err(|e| e.to_string())
+        }
+        "get_git_log" => {
+            let limit = input.get("limit").and_then(|v| v.as_u64()).unwrap_or(20) as u32;
+            let log = crate::git::get_git_log(path, Some(limit), None, None, None, None, None)?;
+            serde_json::to_string(&log).map_err(|e| e.to_string())
+        }
+        _ => Err(format!("Unknown tool: {}", name)),
+    }
+}
+
+/// One-shot completion against the full architect model, for internal
+/// pipelines (PR review, spec work) that need real reasoning.
+pub async fn complete(system: &str, user: &str) -> Result<String, String> {
+    let loaded = settings::load_settings()?;
+    let provider = llm::provider_from_settings(&loaded);
+    provider
+        .chat(
+            &provider.architect_model(),
+            MAX_TOKENS,
+            system,
+            &[ChatMessage {
+                role: "user".to_string(),
+                content: user.to_string(),
+            }],
+            None,
+        )
+        .await
+}
+
+/// One-shot completion against a small model, for lightweight internal
+/// pipelines (summaries, extraction) that don't need the full architect.
+pub async fn complete_simple(system: &str, user: &str) -> Result<String, String> {
+    let loaded = settings::load_settings()?;
+    let provider = llm::provider_from_settings(&loaded);
+    provider
+        .chat(
+            &provider.simple_model(),
+            1024,
+            system,
+            &[ChatMessage {
+                role: "user".to_string(),
+                content: user.to_string(),
+            }],
+            None,
+        )
+        .await
+}
+
+#[derive(Debug, Deserialize)]
+struct WhisperResponse {
+    text: String,
+}
+
+/// Detect the audio container from its magic bytes so browsers (webm),
+/// native recorders (wav, m4a), and phone uploads (ogg) all work. Returns
+/// `(file_name, mime_type)`; unknown data is assumed to be webm.
+pub fn sniff_audio_format(audio: &[u8]) -> (&'static str, &'static str) {
+    if audio.len() >= 12 && &audio[..4] == b"RIFF" && &audio[8..12] == b"WAVE" {
+        return ("audio.wav", "audio/wav");
+    }
+    if audio.starts_with(b"OggS") {
+        return ("audio.ogg", "audio/ogg");
+    }
+    if audio.len() >= 8 && &audio[4..8] == b"ftyp" {
+        return ("audio.m4a", "audio/mp4");
+    }
+    ("audio.webm", "audio/webm")
+}
+
+/// Transcribe a base64-encoded recording with Whisper.
+///
+/// `language` is an ISO 639-1 hint, `prompt` seeds domain vocabulary,
+/// `temperature` controls decoding randomness, and `model` picks the
+/// transcription model (default whisper-1). The container format (webm,
+/// wav, m4a, ogg) is sniffed from the data. When no prompt is given but a
+/// project is, one is built from the project's memory files so identifiers
+/// like "tauri" and "pnpm" survive transcription.
+#[tauri::command]
+pub async fn transcribe_audio(
+    audio_base64: String,
+    language: Option<String>,
+    prompt: Option<String>,
+    temperature: Option<f32>,
+    model: Option<String>,
+    project_path: Option<String>,
+) -> Result<String, String> {
+    let loaded = settings::load_settings()?;
+
+    let audio = base64::engine::general_purpose::STANDARD
+        .decode(&audio_base64)
+        .map_err(|e| format!("Invalid audio data: {}", e))?;
+
+    // Without a cloud key, offline whisper.cpp is the only option.
+    if loaded.openai_api_key.is_empty() {
+        if whisper_cpp_configured(&loaded) {
+            return transcribe_locally(&loaded, &audio, language.as_deref());
+        }
+        return Err("OpenAI API key not configured".to_string());
+    }
+
+    let prompt = prompt.or_else(|| project_path.as_deref().and_then(build_domain_prompt));
+
+    let (file_name, mime_type) = sniff_audio_format(&audio);
+    let part = reqwest::multipart::Part::bytes(audio.clone())
+        .file_name(file_name)
+        .mime_str(mime_type)
+        .map_err(|e| e.to_string())?;
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", part)
+        .text("model", model.unwrap_or_else(|| "whisper-1".to_string()));
+    if let Some(language) = language {
+        form = form.text("language", language);
+    }
+    if let Some(prompt) = prompt {
+        form = form.text("prompt", prompt);
+    }
+    if let Some(temperature) = temperature {
+        form = form.text("temperature", temperature.clamp(0.0, 1.0).to_string());
+    }
+
+    rate_limit::acquire(rate_limit::Provider::OpenAi).await;
+    let client = reqwest::Client::new();
+    let response = match client
+        .post("https://api.openai.com/v1/audio/transcriptions")
+        .bearer_auth(&loaded.openai_api_key)
+        .multipart(form)
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        // Network down: fall back to the local model rather than losing the
+        // recording.
+        Err(e) if whisper_cpp_configured(&loaded) => {
+            log::warn!("Whisper API unreachable ({}); transcribing locally", e);
+            return transcribe_locally(&loaded, &audio, language.as_deref());
+        }
+        Err(e) => return Err(format!("Transcription request failed: {}", e)),
+    };
+
+    if !response.status().is_success() {
+        return Err(format!("Whisper API error: {}", response.status()));
+    }
+
+    let parsed: WhisperResponse = response.json().await.map_err(|e| e.to_string())?;
+    Ok(parsed.text)
+}
+
+fn whisper_cpp_configured(loaded: &settings::Settings) -> bool {
+    !loaded.whisper_cpp_path.is_empty() && !loaded.whisper_model_path.is_empty()
+}
+
+/// Transcribe with a local whisper.cpp CLI build. The audio is written to a
+/// temp file and handed to the binary configured in settings; stdout (with
+/// timestamps suppressed) is the transcript.
+fn transcribe_locally(
+    loaded: &settings::Settings,
+    audio: &[u8],
+    language: Option<&str>,
+) -> Result<String, String> {
+    let (file_name, _) = sniff_audio_format(audio);
+    let extension = file_name.rsplit('.').next().unwrap_or("webm");
+    let input = std::env::temp_dir().join(format!(
+        "sentra-transcribe-{}.{}",
+        uuid::Uuid::new_v4(),
+        extension
+    ));
+    std::fs::write(&input, audio).map_err(|e| e.to_string())?;
+
+    let mut cmd = std::process::Command::new(&loaded.whisper_cpp_path);
+    cmd.args(["-m", &loaded.whisper_model_path, "--no-timestamps", "-f"])
+        .arg(&input);
+    if let Some(language) = language {
+        cmd.args(["-l", language]);
+    }
+    let output = cmd.output();
+    let _ = std::fs::remove_file(&input);
+
+    let output = output.map_err(|e| format!("Failed to run whisper.cpp: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "whisper.cpp failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Whisper prompts are capped around 224 tokens; stay well under.
+const MAX_PROMPT_TERMS: usize = 60;
+
+/// Pull project/tech vocabulary (backticked terms and headings) out of the
+/// project's memory files to seed Whisper with.
+fn build_domain_prompt(project_path: &str) -> Option<String> {
+    let memory = crate::commands::get_project_memory(project_path.to_string()).ok()?;
+    if memory.is_empty() {
+        return None;
+    }
+
+    let mut terms: Vec<String> = Vec::new();
+    let mut push_unique = |term: &str| {
+        let term = term.trim();
+        if term.is_empty() || term.len() > 40 || terms.iter().any(|t| t == term) {
+            return;
+        }
+        terms.push(term.to_string());
+    };
+
+    for line in memory.lines() {
+        if let Some(heading) = line.strip_prefix("### ").or_else(|| line.strip_prefix("## ")) {
+            push_unique(heading);
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find('`') {
+            let after = &rest[start + 1..];
+            let Some(end) = after.find('`') else { break };
+            push_unique(&after[..end]);
+            rest = &after[end + 1..];
+        }
+    }
+
+    terms.truncate(MAX_PROMPT_TERMS);
+    if terms.is_empty() {
+        None
+    } else {
+        Some(format!("Vocabulary: {}.", terms.join(", ")))
+    }
+}
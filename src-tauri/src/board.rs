@@ -0,0 +1,165 @@
+//! Kanban board state.
+//!
+//! Maps specs and issues into columns (Backlog, Speccing, Agent Running,
+//! In Review, Done), persisted per project at `<project>/.sentra/board.json`.
+//! Cards move manually via `move_card` or automatically as specs progress and
+//! agent / PR events arrive.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::specs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardColumn {
+    Backlog,
+    Speccing,
+    AgentRunning,
+    InReview,
+    Done,
+}
+
+/// Triggers that move cards automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardTrigger {
+    SpecCreated,
+    SpecApproved,
+    AgentStarted,
+    PrOpened,
+    PrMerged,
+}
+
+impl BoardTrigger {
+    fn target_column(self) -> BoardColumn {
+        match self {
+            BoardTrigger::SpecCreated => BoardColumn::Speccing,
+            BoardTrigger::SpecApproved => BoardColumn::Backlog,
+            BoardTrigger::AgentStarted => BoardColumn::AgentRunning,
+            BoardTrigger::PrOpened => BoardColumn::InReview,
+            BoardTrigger::PrMerged => BoardColumn::Done,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Card {
+    /// Spec id or issue reference the card represents.
+    pub id: String,
+    pub title: String,
+    pub column: BoardColumn,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Board {
+    #[serde(default)]
+    pub cards: Vec<Card>,
+}
+
+fn board_path(project_path: &Path) -> PathBuf {
+    project_path.join(".sentra").join("board.json")
+}
+
+pub fn load_board(project_path: &Path) -> Board {
+    fs::read_to_string(board_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_board(project_path: &Path, board: &Board) -> Result<(), String> {
+    let path = board_path(project_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(board).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+/// Put a card in a column, creating it if it doesn't exist yet.
+pub fn set_column(
+    project_path: &Path,
+    card_id: &str,
+    title: &str,
+    column: BoardColumn,
+) -> Result<(), String> {
+    let mut board = load_board(project_path);
+    let now = Utc::now().to_rfc3339();
+    match board.cards.iter_mut().find(|c| c.id == card_id) {
+        Some(card) => {
+            card.column = column;
+            card.updated_at = now;
+            if !title.is_empty() {
+                card.title = title.to_string();
+            }
+        }
+        None => board.cards.push(Card {
+            id: card_id.to_string(),
+            title: title.to_string(),
+            column,
+            updated_at: now,
+        }),
+    }
+    save_board(project_path, &board)
+}
+
+/// Apply an automatic transition for an agent / spec / PR event.
+pub fn apply_trigger(
+    project_path: &Path,
+    card_id: &str,
+    title: &str,
+    trigger: BoardTrigger,
+) -> Result<(), String> {
+    set_column(project_path, card_id, title, trigger.target_column())
+}
+
+/// The board for a project, with any specs missing from it backfilled into
+/// Speccing (draft) or Backlog (approved).
+#[tauri::command]
+pub fn get_board(project_path: String) -> Result<Board, String> {
+    let path = Path::new(&project_path);
+    let mut board = load_board(path);
+
+    let mut dirty = false;
+    for spec in specs::list_specs_for_project(path).unwrap_or_default() {
+        if board.cards.iter().any(|c| c.id == spec.id) {
+            continue;
+        }
+        board.cards.push(Card {
+            id: spec.id,
+            title: spec.title,
+            column: if spec.approved {
+                BoardColumn::Backlog
+            } else {
+                BoardColumn::Speccing
+            },
+            updated_at: spec.updated_at,
+        });
+        dirty = true;
+    }
+    if dirty {
+        save_board(path, &board)?;
+    }
+    Ok(board)
+}
+
+/// Manually move a card to a column.
+#[tauri::command]
+pub fn move_card(project_path: String, card_id: String, column: BoardColumn) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let mut board = load_board(path);
+    let card = board
+        .cards
+        .iter_mut()
+        .find(|c| c.id == card_id)
+        .ok_or_else(|| format!("Unknown card: {}", card_id))?;
+    card.column = column;
+    card.updated_at = Utc::now().to_rfc3339();
+    save_board(path, &board)
+}
@@ -0,0 +1,200 @@
+//! Local pre-merge checks.
+//!
+//! Runs a project's configured quality commands (lint, type-check, tests)
+//! locally with streamed output, so an agent branch can be verified from the
+//! dashboard before approving. Checks come from `.sentra/checks.json` when
+//! present, otherwise from stack detection.
+
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::events;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckSpec {
+    pub name: String,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    pub name: String,
+    pub command: String,
+    pub success: bool,
+    pub duration_seconds: f64,
+    /// The last lines of output, for failures at a glance.
+    pub output_tail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckSummary {
+    pub results: Vec<CheckResult>,
+    pub passed: bool,
+}
+
+/// Payload for `check-output` events streamed while a check runs.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckOutput {
+    project: String,
+    check: String,
+    line: String,
+}
+
+const OUTPUT_TAIL_LINES: usize = 40;
+
+/// Checks configured in `.sentra/checks.json`, or stack defaults.
+pub fn project_checks(project_path: &Path) -> Vec<CheckSpec> {
+    let config = project_path.join(".sentra").join("checks.json");
+    if let Ok(content) = std::fs::read_to_string(&config) {
+        if let Ok(checks) = serde_json::from_str::<Vec<CheckSpec>>(&content) {
+            return checks;
+        }
+    }
+
+    let spec = |name: &str, command: &str| CheckSpec {
+        name: name.to_string(),
+        command: command.to_string(),
+    };
+    if project_path.join("package.json").exists() {
+        vec![
+            spec("lint", "npm run lint"),
+            spec("type-check", "npm run type-check"),
+            spec("test", "npm test -- --run"),
+        ]
+    } else if project_path.join("Cargo.toml").exists() {
+        vec![
+            spec("build", "cargo build"),
+            spec("clippy", "cargo clippy --all-targets -- -D warnings"),
+            spec("test", "cargo test"),
+        ]
+    } else if project_path.join("pyproject.toml").exists() {
+        vec![spec("lint", "ruff check ."), spec("test", "pytest")]
+    } else if project_path.join("go.mod").exists() {
+        vec![spec("vet", "go vet ./..."), spec("test", "go test ./...")]
+    } else {
+        Vec::new()
+    }
+}
+
+fn run_check(
+    app: &AppHandle,
+    project: &str,
+    path: &Path,
+    check: &CheckSpec,
+) -> CheckResult {
+    let started = Instant::now();
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/C", &check.command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", &check.command]);
+        c
+    };
+    let child = cmd
+        .current_dir(path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            return CheckResult {
+                name: check.name.clone(),
+                command: check.command.clone(),
+                success: false,
+                duration_seconds: started.elapsed().as_secs_f64(),
+                output_tail: e.to_string(),
+            }
+        }
+    };
+
+    let mut tail: Vec<String> = Vec::new();
+    let mut capture = |line: String| {
+        events::emit_scoped(
+            app,
+            "check-output",
+            CheckOutput {
+                project: project.to_string(),
+                check: check.name.clone(),
+                line: line.clone(),
+            },
+        );
+        tail.push(line);
+        if tail.len() > OUTPUT_TAIL_LINES {
+            tail.remove(0);
+        }
+    };
+
+    // stderr is read on a helper thread so neither pipe can fill and stall
+    // the child.
+    let stderr_lines = child.stderr.take().map(|stderr| {
+        std::thread::spawn(move || {
+            BufReader::new(stderr)
+                .lines()
+                .map_while(Result::ok)
+                .collect::<Vec<_>>()
+        })
+    });
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            capture(line);
+        }
+    }
+    if let Some(handle) = stderr_lines {
+        for line in handle.join().unwrap_or_default() {
+            capture(line);
+        }
+    }
+
+    let success = child.wait().map(|s| s.success()).unwrap_or(false);
+    CheckResult {
+        name: check.name.clone(),
+        command: check.command.clone(),
+        success,
+        duration_seconds: started.elapsed().as_secs_f64(),
+        output_tail: tail.join("\n"),
+    }
+}
+
+/// Run the project's checks (all of them, or just the named subset) and
+/// return a structured pass/fail summary. Output streams as `check-output`
+/// events while each check runs.
+#[tauri::command]
+pub fn run_project_checks(
+    app: AppHandle,
+    project_path: String,
+    checks: Option<Vec<String>>,
+) -> Result<CheckSummary, String> {
+    let path = Path::new(&project_path);
+    let project = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut specs = project_checks(path);
+    if let Some(names) = &checks {
+        specs.retain(|s| names.contains(&s.name));
+    }
+    if specs.is_empty() {
+        return Err("No checks configured for this project".to_string());
+    }
+
+    let results: Vec<CheckResult> = specs
+        .iter()
+        .map(|spec| run_check(&app, &project, path, spec))
+        .collect();
+    let passed = results.iter().all(|r| r.success);
+    Ok(CheckSummary { results, passed })
+}
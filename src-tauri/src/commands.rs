@@ -0,0 +1,1141 @@
+//! Core IPC command handlers.
+//!
+//! Project tracking lives in `~/.claude/tracked-projects.txt` (one absolute
+//! path per line). Each tracked project keeps its Sentra data in a `.sentra/`
+//! directory (specs, config) and its memory in `.claude/memory/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::specs;
+use crate::templates;
+
+/// Root for machine-level Sentra data (`~/.claude`).
+pub fn claude_home() -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .map(|h| h.join(".claude"))
+        .ok_or_else(|| "Could not determine home directory".to_string())
+}
+
+/// The flat registry of tracked project paths.
+pub fn tracked_projects_file() -> Result<PathBuf, String> {
+    Ok(claude_home()?.join("tracked-projects.txt"))
+}
+
+/// Where agent workflow logs are mirrored locally.
+pub fn telemetry_dir() -> Result<PathBuf, String> {
+    Ok(claude_home()?.join("telemetry"))
+}
+
+/// Read the tracked-projects registry, skipping blank lines and paths that
+/// no longer exist on disk.
+pub fn read_tracked_projects() -> Result<Vec<PathBuf>, String> {
+    let file = tracked_projects_file()?;
+    if !file.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&file).map_err(|e| e.to_string())?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(PathBuf::from)
+        .filter(|p| p.exists())
+        .collect())
+}
+
+/// Read one `key: value` line from a project's `.sentra/config.yml`. The
+/// file is flat (see `import_project`), so no YAML parser is needed.
+pub fn project_config_value(root: &Path, key: &str) -> Option<String> {
+    let content = fs::read_to_string(root.join(".sentra").join("config.yml")).ok()?;
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix(&format!("{}:", key)))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Resolve a tracked project name or absolute path to its root directory.
+pub fn resolve_project_path(arg: &str) -> Result<PathBuf, String> {
+    let as_path = PathBuf::from(arg);
+    if as_path.is_absolute() && as_path.exists() {
+        return Ok(as_path);
+    }
+    read_tracked_projects()?
+        .into_iter()
+        .find(|p| p.file_name().map_or(false, |n| n == arg))
+        .ok_or_else(|| format!("Unknown project: {}", arg))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    pub name: String,
+    pub path: String,
+    pub total_issues: u32,
+    pub completed_issues: u32,
+    pub spec_count: usize,
+    pub pending_spec_count: usize,
+    pub has_git: bool,
+    /// Voice notifications for this project are suppressed.
+    pub muted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardStats {
+    pub total_projects: usize,
+    pub active_agents: u32,
+    pub today_cost: f64,
+    pub monthly_cost: f64,
+    pub monthly_budget: f64,
+    pub success_rate: f64,
+}
+
+/// List every tracked project with its spec counts.
+#[tauri::command]
+pub async fn get_projects() -> Result<Vec<Project>, String> {
+    if let Some(projects) = PROJECTS_CACHE.lock().unwrap().clone() {
+        return Ok(projects);
+    }
+    // Each scan reads spec metadata and may shell out to gh; fan them out
+    // on blocking threads instead of serializing on the IPC thread.
+    let handles: Vec<_> = read_tracked_projects()?
+        .into_iter()
+        .map(|path| tokio::task::spawn_blocking(move || scan_project(path)))
+        .collect();
+    let mut projects = Vec::new();
+    for result in futures_util::future::join_all(handles).await {
+        projects.push(result.map_err(|e| e.to_string())?);
+    }
+    *PROJECTS_CACHE.lock().unwrap() = Some(projects.clone());
+    Ok(projects)
+}
+
+/// Synchronous variant for callers already off the async runtime (the
+/// realtime proxy's tool bodies). Shares the same cache.
+pub fn get_projects_blocking() -> Result<Vec<Project>, String> {
+    if let Some(projects) = PROJECTS_CACHE.lock().unwrap().clone() {
+        return Ok(projects);
+    }
+    let projects: Vec<Project> = read_tracked_projects()?.into_iter().map(scan_project).collect();
+    *PROJECTS_CACHE.lock().unwrap() = Some(projects.clone());
+    Ok(projects)
+}
+
+static PROJECTS_CACHE: std::sync::Mutex<Option<Vec<Project>>> = std::sync::Mutex::new(None);
+
+/// Drop the cached project list. The file watcher calls this when the
+/// tracked-projects registry or a spec directory changes.
+pub fn invalidate_projects_cache() {
+    *PROJECTS_CACHE.lock().unwrap() = None;
+}
+
+/// One project's card data: spec counts from disk, issue counts from GitHub.
+fn scan_project(path: PathBuf) -> Project {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string());
+    let spec_infos = specs::list_specs_for_project(&path).unwrap_or_default();
+    let pending = specs::pending_specs_dir(&path)
+        .and_then(|d| fs::read_dir(d).ok())
+        .map(|entries| entries.filter_map(|e| e.ok()).count())
+        .unwrap_or(0);
+    let has_git = path.join(".git").exists();
+    let (total_issues, completed_issues) = if has_git {
+        github_issue_counts(&path)
+    } else {
+        (0, 0)
+    };
+    Project {
+        name,
+        path: path.display().to_string(),
+        total_issues,
+        completed_issues,
+        spec_count: spec_infos.len(),
+        pending_spec_count: pending,
+        has_git,
+        muted: is_project_muted(&path.display().to_string()),
+    }
+}
+
+/// Issue counts refresh this often; two gh calls per project per lookup is
+/// too expensive for every dashboard render.
+const ISSUE_COUNT_TTL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+#[allow(clippy::type_complexity)]
+static ISSUE_COUNTS: std::sync::Mutex<
+    Option<std::collections::HashMap<String, (std::time::Instant, (u32, u32))>>,
+> = std::sync::Mutex::new(None);
+
+fn count_issues(project_path: &Path, state: &str) -> Option<u32> {
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args([
+            "issue", "list", "--state", state, "--limit", "1000", "--json", "number",
+        ])
+        .current_dir(project_path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let issues: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).ok()?;
+    Some(issues.len() as u32)
+}
+
+/// (total, completed) issue counts for a project's GitHub repo, cached.
+/// Failures (no remote, gh unauthenticated) cache as zeros so one broken
+/// project doesn't re-probe GitHub on every call.
+fn github_issue_counts(project_path: &Path) -> (u32, u32) {
+    let key = project_path.display().to_string();
+    {
+        let cache = ISSUE_COUNTS.lock().unwrap();
+        if let Some((fetched_at, counts)) = cache.as_ref().and_then(|map| map.get(&key)) {
+            if fetched_at.elapsed() < ISSUE_COUNT_TTL {
+                return *counts;
+            }
+        }
+    }
+
+    let open = count_issues(project_path, "open").unwrap_or(0);
+    let closed = count_issues(project_path, "closed").unwrap_or(0);
+    let counts = (open + closed, closed);
+    ISSUE_COUNTS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(std::collections::HashMap::new)
+        .insert(key, (std::time::Instant::now(), counts));
+    counts
+}
+
+/// Create a new project directory from a template and register it.
+#[tauri::command]
+pub fn create_project(
+    app: tauri::AppHandle,
+    name: String,
+    parent_dir: String,
+    template: String,
+) -> Result<Project, String> {
+    let path = Path::new(&parent_dir).join(&name);
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+
+    let resolved = templates::find_template(&template)?;
+    templates::apply_template(&path, &template)?;
+    scaffold_sentra_dir(&path)?;
+
+    // git init so agents have a repo to work against from day one.
+    let _ = Command::new("git").arg("init").current_dir(&path).output();
+
+    // Installs can take minutes; they stream in the background while the
+    // project shows up immediately.
+    templates::run_post_create_commands(app, path.clone(), resolved.post_create_commands);
+
+    track_project(&path)?;
+
+    Ok(Project {
+        name,
+        path: path.display().to_string(),
+        total_issues: 0,
+        completed_issues: 0,
+        spec_count: 0,
+        pending_spec_count: 0,
+        has_git: true,
+        muted: false,
+    })
+}
+
+/// Append a path to the tracked-projects registry, skipping duplicates.
+fn track_project(path: &Path) -> Result<(), String> {
+    let file = tracked_projects_file()?;
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let mut content = if file.exists() {
+        fs::read_to_string(&file).map_err(|e| e.to_string())?
+    } else {
+        String::new()
+    };
+    let line = path.display().to_string();
+    if content.lines().any(|l| l.trim() == line) {
+        return Ok(());
+    }
+    if !content.ends_with('\n') && !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&format!("{}\n", line));
+    fs::write(&file, content).map_err(|e| e.to_string())?;
+    invalidate_projects_cache();
+    Ok(())
+}
+
+/// The stack a directory appears to use, from its manifest files.
+fn detect_stack(path: &Path) -> &'static str {
+    if path.join("package.json").exists() {
+        "node"
+    } else if path.join("Cargo.toml").exists() {
+        "rust"
+    } else if path.join("pyproject.toml").exists() || path.join("requirements.txt").exists() {
+        "python"
+    } else if path.join("go.mod").exists() {
+        "go"
+    } else {
+        "unknown"
+    }
+}
+
+/// Track an existing directory as a project: scaffold the `.sentra/`
+/// structure around whatever is already there (never overwriting existing
+/// files) and register it. Complements `create_project`, which only builds
+/// brand-new directories.
+#[tauri::command]
+pub fn import_project(app: tauri::AppHandle, project_path: String) -> Result<Project, String> {
+    let path = PathBuf::from(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Not a directory: {}", project_path));
+    }
+
+    scaffold_sentra_dir(&path)?;
+    // Record the detected stack so checks and templates can consult it.
+    let config = path.join(".sentra").join("config.yml");
+    if !config.exists() {
+        let stack = detect_stack(&path);
+        fs::write(&config, format!("stack: {}\n", stack)).map_err(|e| e.to_string())?;
+    }
+
+    track_project(&path)?;
+    use tauri::Emitter;
+    let _ = app.emit("projects-updated", ());
+    Ok(scan_project(path))
+}
+
+/// Drop a path from the tracked-projects registry. Absent paths are not an
+/// error; the registry may already have been edited by hand.
+fn untrack_project(project_path: &str) -> Result<(), String> {
+    let file = tracked_projects_file()?;
+    if !file.exists() {
+        return Ok(());
+    }
+    let content = fs::read_to_string(&file).map_err(|e| e.to_string())?;
+    let remaining: Vec<&str> = content
+        .lines()
+        .filter(|line| line.trim() != project_path)
+        .collect();
+    let mut updated = remaining.join("\n");
+    if !updated.is_empty() {
+        updated.push('\n');
+    }
+    fs::write(&file, updated).map_err(|e| e.to_string())?;
+    invalidate_projects_cache();
+    Ok(())
+}
+
+/// Untrack a project, optionally deleting its directory from disk.
+#[tauri::command]
+pub fn remove_project(
+    app: tauri::AppHandle,
+    project_path: String,
+    delete_files: bool,
+) -> Result<(), String> {
+    untrack_project(&project_path)?;
+    if delete_files {
+        let path = Path::new(&project_path);
+        if path.exists() {
+            fs::remove_dir_all(path).map_err(|e| e.to_string())?;
+        }
+    }
+    use tauri::Emitter;
+    let _ = app.emit("projects-updated", ());
+    Ok(())
+}
+
+/// Untrack a project but preserve its Sentra data: the project's `.sentra`
+/// directory is moved into `~/.claude/sentra/archive/<name>/` so specs and
+/// config survive if the project comes back later.
+#[tauri::command]
+pub fn archive_project(app: tauri::AppHandle, project_path: String) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Invalid project path: {}", project_path))?;
+
+    let sentra = path.join(".sentra");
+    if sentra.exists() {
+        let archive = crate::settings::sentra_dir()?.join("archive").join(&name);
+        if let Some(parent) = archive.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        if archive.exists() {
+            fs::remove_dir_all(&archive).map_err(|e| e.to_string())?;
+        }
+        // rename fails across filesystems; fall back to copy + delete.
+        if fs::rename(&sentra, &archive).is_err() {
+            copy_dir(&sentra, &archive)?;
+            fs::remove_dir_all(&sentra).map_err(|e| e.to_string())?;
+        }
+    }
+
+    untrack_project(&project_path)?;
+    use tauri::Emitter;
+    let _ = app.emit("projects-updated", ());
+    Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), String> {
+    fs::create_dir_all(to).map_err(|e| e.to_string())?;
+    for entry in fs::read_dir(from).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let dest = to.join(entry.file_name());
+        if entry.path().is_dir() {
+            copy_dir(&entry.path(), &dest)?;
+        } else {
+            fs::copy(entry.path(), &dest).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Lay down the `.sentra/` skeleton inside a project directory.
+pub fn scaffold_sentra_dir(project_path: &Path) -> Result<(), String> {
+    let sentra = project_path.join(".sentra");
+    fs::create_dir_all(sentra.join("specs").join(".metadata")).map_err(|e| e.to_string())?;
+    fs::create_dir_all(sentra.join("specs").join("pending")).map_err(|e| e.to_string())?;
+    fs::create_dir_all(project_path.join(".claude").join("memory")).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Stats answers are cached this long; computing them shells out to gh per
+/// project, which is far too slow for a header that re-renders often.
+const DASHBOARD_STATS_TTL: std::time::Duration = std::time::Duration::from_secs(30);
+
+static STATS_CACHE: std::sync::Mutex<Option<(std::time::Instant, DashboardStats)>> =
+    std::sync::Mutex::new(None);
+
+/// Aggregate stats for the dashboard header: active agents from workflow
+/// runs plus local agents, costs from the usage ledger, and success rate
+/// over the last `window` completed runs (default 50).
+#[tauri::command]
+pub fn get_dashboard_stats(window: Option<u32>) -> Result<DashboardStats, String> {
+    if let Some((computed_at, stats)) = STATS_CACHE.lock().unwrap().as_ref() {
+        if computed_at.elapsed() < DASHBOARD_STATS_TTL {
+            return Ok(stats.clone());
+        }
+    }
+
+    let projects = read_tracked_projects()?;
+    let loaded = crate::settings::load_settings()?;
+
+    let remote_agents = crate::agents::get_active_agents()
+        .map(|agents| agents.len())
+        .unwrap_or(0);
+    let local_agents = crate::agent_runner::list_local_agents()?
+        .iter()
+        .filter(|a| a.status == crate::agent_runner::LocalAgentStatus::Running)
+        .count();
+
+    let today_start = crate::time_tracking::range_start("today", chrono::Utc::now())?;
+    let today_cost = crate::costs::spend_since(today_start, None)?;
+    let monthly_cost = crate::costs::spend_since(crate::costs::current_month_start(), None)?;
+
+    let history = crate::agents::get_agent_history(window.or(Some(50))).unwrap_or_default();
+    let concluded = history.iter().filter(|a| a.conclusion.is_some()).count();
+    let succeeded = history
+        .iter()
+        .filter(|a| a.conclusion.as_deref() == Some("success"))
+        .count();
+    let success_rate = if concluded == 0 {
+        0.0
+    } else {
+        succeeded as f64 / concluded as f64
+    };
+
+    let stats = DashboardStats {
+        total_projects: projects.len(),
+        active_agents: (remote_agents + local_agents) as u32,
+        today_cost,
+        monthly_cost,
+        monthly_budget: loaded.monthly_budget,
+        success_rate,
+    };
+    *STATS_CACHE.lock().unwrap() = Some((std::time::Instant::now(), stats.clone()));
+    Ok(stats)
+}
+
+fn muted_projects_file() -> Result<PathBuf, String> {
+    Ok(crate::settings::sentra_dir()?.join("muted-projects.json"))
+}
+
+fn read_muted_projects() -> Vec<String> {
+    let Ok(path) = muted_projects_file() else {
+        return Vec::new();
+    };
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Whether a project is muted, matched by path or by directory name (the
+/// notification pipeline only knows project names).
+pub fn is_project_muted(project: &str) -> bool {
+    read_muted_projects().iter().any(|entry| {
+        entry == project
+            || Path::new(entry)
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy() == project)
+    })
+}
+
+/// Mute or unmute voice notifications for a project. Persisted in
+/// `~/.claude/sentra/muted-projects.json`.
+#[tauri::command]
+pub fn set_project_muted(
+    app: tauri::AppHandle,
+    project_path: String,
+    muted: bool,
+) -> Result<(), String> {
+    let mut entries = read_muted_projects();
+    entries.retain(|entry| entry != &project_path);
+    if muted {
+        entries.push(project_path);
+    }
+    let path = muted_projects_file()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+
+    invalidate_projects_cache();
+    use tauri::Emitter;
+    let _ = app.emit("projects-updated", ());
+    Ok(())
+}
+
+/// The tracked project whose GitHub repo owns a workflow run, found by
+/// probing `gh run view` in each candidate.
+fn find_run_project(agent_id: &str) -> Result<PathBuf, String> {
+    for path in read_tracked_projects()? {
+        if !path.join(".git").exists() {
+            continue;
+        }
+        crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+        let found = Command::new("gh")
+            .args(["run", "view", agent_id, "--json", "databaseId"])
+            .current_dir(&path)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+        if found {
+            return Ok(path);
+        }
+    }
+    Err(format!("No tracked project owns run {}", agent_id))
+}
+
+/// Stop a running agent by cancelling its GitHub Actions workflow run.
+#[tauri::command]
+pub fn stop_agent(
+    app: tauri::AppHandle,
+    agent_id: String,
+    project_path: Option<String>,
+) -> Result<(), String> {
+    let path = match project_path {
+        Some(p) => PathBuf::from(p),
+        None => find_run_project(&agent_id)?,
+    };
+
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args(["run", "cancel", &agent_id])
+        .current_dir(&path)
+        .output()
+        .map_err(|e| format!("Failed to run gh: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let project = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    crate::activity::record_event(
+        crate::activity::ActivityEventType::AgentComplete,
+        &project,
+        &format!("Agent {} cancelled", agent_id),
+    );
+    use tauri::Emitter;
+    let _ = app.emit("agents-updated", ());
+    Ok(())
+}
+
+/// Rough token estimate (≈4 chars per token) used for context budgeting.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectContext {
+    pub text: String,
+    pub estimated_tokens: usize,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextSectionKind {
+    Memory,
+    GlobalMemory,
+    Manifest,
+    Readme,
+    Tree,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextSection {
+    pub title: String,
+    pub kind: ContextSectionKind,
+    /// File the section came from, when it maps to a single file.
+    pub source_path: Option<String>,
+    pub bytes: usize,
+    pub content: String,
+}
+
+fn section(
+    title: impl Into<String>,
+    kind: ContextSectionKind,
+    source_path: Option<String>,
+    content: String,
+) -> ContextSection {
+    ContextSection {
+        title: title.into(),
+        kind,
+        source_path,
+        bytes: content.len(),
+        content,
+    }
+}
+
+/// Context sections in priority order: memory > manifest > README > tree.
+/// Higher-priority sections survive tight budgets intact.
+fn gather_context_sections(path: &Path) -> Vec<ContextSection> {
+    let mut sections = Vec::new();
+
+    let memory = crate::memory::read_all(path);
+    if !memory.is_empty() {
+        let source = crate::memory::memory_dir(path).display().to_string();
+        sections.push(section(
+            "Project Memory",
+            ContextSectionKind::Memory,
+            Some(source),
+            memory,
+        ));
+    }
+
+    // Machine-level conventions sit beneath project memory so project
+    // entries win when the two disagree.
+    let global = crate::memory::read_global();
+    if !global.is_empty() {
+        let source = crate::memory::global_memory_dir()
+            .ok()
+            .map(|d| d.display().to_string());
+        sections.push(section(
+            "Global Conventions",
+            ContextSectionKind::GlobalMemory,
+            source,
+            global,
+        ));
+    }
+
+    for manifest in ["package.json", "Cargo.toml", "pyproject.toml"] {
+        let manifest_path = path.join(manifest);
+        if let Ok(content) = fs::read_to_string(&manifest_path) {
+            sections.push(section(
+                format!("Manifest ({})", manifest),
+                ContextSectionKind::Manifest,
+                Some(manifest_path.display().to_string()),
+                content,
+            ));
+            break;
+        }
+    }
+
+    for readme in ["README.md", "readme.md", "Readme.md"] {
+        let readme_path = path.join(readme);
+        if let Ok(content) = fs::read_to_string(&readme_path) {
+            sections.push(section(
+                "README",
+                ContextSectionKind::Readme,
+                Some(readme_path.display().to_string()),
+                content,
+            ));
+            break;
+        }
+    }
+
+    let patterns = gitignore_patterns(path);
+    let mut tree = String::new();
+    render_tree(path, 0, &patterns, &mut tree);
+    sections.push(section(
+        "Project Structure",
+        ContextSectionKind::Tree,
+        None,
+        tree,
+    ));
+
+    sections
+}
+
+/// How deep the "Project Structure" tree descends. Directories below this
+/// still appear with a file count, just not their contents.
+const MAX_TREE_DEPTH: usize = 3;
+
+/// Root `.gitignore` patterns, reduced to the simple forms that matter for a
+/// layout summary: bare names, rooted/trailing-slash entries, and `*.ext`
+/// globs. Negations are ignored.
+fn gitignore_patterns(root: &Path) -> Vec<String> {
+    fs::read_to_string(root.join(".gitignore"))
+        .map(|content| {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with('!'))
+                .map(|l| l.trim_start_matches('/').trim_end_matches('/').to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_ignored(name: &str, patterns: &[String]) -> bool {
+    if name.starts_with('.') || name == "node_modules" || name == "target" {
+        return true;
+    }
+    patterns.iter().any(|p| {
+        if let Some(suffix) = p.strip_prefix('*') {
+            name.ends_with(suffix)
+        } else {
+            name == p
+        }
+    })
+}
+
+/// Files under `dir` (recursively), skipping ignored entries.
+fn count_files(dir: &Path, patterns: &[String]) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut count = 0;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if is_ignored(&name, patterns) {
+            continue;
+        }
+        if entry.path().is_dir() {
+            count += count_files(&entry.path(), patterns);
+        } else {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Indented directory listing, directories first, depth-limited so a deeply
+/// nested `src/app` still reads as a summary rather than a dump.
+fn render_tree(dir: &Path, depth: usize, patterns: &[String], out: &mut String) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if is_ignored(&name, patterns) {
+            continue;
+        }
+        if entry.path().is_dir() {
+            dirs.push((name, entry.path()));
+        } else {
+            files.push(name);
+        }
+    }
+    dirs.sort();
+    files.sort();
+
+    let indent = "  ".repeat(depth);
+    for (name, path) in dirs {
+        let file_count = count_files(&path, patterns);
+        out.push_str(&format!("{}- {}/ ({} files)\n", indent, name, file_count));
+        if depth + 1 < MAX_TREE_DEPTH {
+            render_tree(&path, depth + 1, patterns, out);
+        }
+    }
+    for name in files {
+        out.push_str(&format!("{}- {}\n", indent, name));
+    }
+}
+
+/// Structured variant of [`get_project_context`]: individual sections with
+/// kind, source, and size, so callers (architect tools, agent dispatch) can
+/// choose what to include instead of always sending everything.
+#[tauri::command]
+pub fn get_project_context_sections(project_path: String) -> Result<Vec<ContextSection>, String> {
+    Ok(gather_context_sections(Path::new(&project_path)))
+}
+
+const STANDUP_SYSTEM: &str = "You turn raw project activity (commits, merged \
+PRs, agent runs, events) into a short spoken-style standup summary: what got \
+done, what's in flight, anything that failed. Plain prose, a few sentences, \
+no markdown.";
+
+#[derive(Debug, Deserialize)]
+struct StandupPr {
+    title: String,
+    #[serde(rename = "mergedAt")]
+    merged_at: String,
+}
+
+/// Merged PRs for the standup window, via gh in the project directory.
+fn merged_prs_since(path: &Path, start: &str) -> Vec<String> {
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args([
+            "pr", "list", "--state", "merged", "--limit", "50", "--json", "title,mergedAt",
+        ])
+        .current_dir(path)
+        .output();
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    serde_json::from_slice::<Vec<StandupPr>>(&output.stdout)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pr| pr.merged_at.as_str() >= start)
+        .map(|pr| pr.title)
+        .collect()
+}
+
+/// Build a standup summary from git log, merged PRs, agent history, and
+/// activity events over a range ("today", "week", "month"). With `polish`
+/// the raw digest is rewritten by Claude into natural prose; with `speak`
+/// the result is also queued for TTS.
+#[tauri::command]
+pub async fn generate_standup(
+    project_path: String,
+    since: Option<String>,
+    polish: Option<bool>,
+    speak: Option<bool>,
+) -> Result<String, String> {
+    let range = since.unwrap_or_else(|| "today".to_string());
+    let start = crate::time_tracking::range_start(&range, chrono::Utc::now())?.to_rfc3339();
+    let path = Path::new(&project_path);
+    let project = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut digest = format!("Standup for {} ({}):\n", project, range);
+
+    if path.join(".git").exists() {
+        if let Ok(log) = crate::git::run_git(
+            path,
+            &["log", "--since", &start, "--pretty=format:%s"],
+        ) {
+            let commits: Vec<&str> = log.lines().filter(|l| !l.is_empty()).collect();
+            if !commits.is_empty() {
+                digest.push_str(&format!("\nCommits ({}):\n", commits.len()));
+                for subject in commits.iter().take(20) {
+                    digest.push_str(&format!("- {}\n", subject));
+                }
+            }
+        }
+        let merged = merged_prs_since(path, &start);
+        if !merged.is_empty() {
+            digest.push_str(&format!("\nMerged PRs ({}):\n", merged.len()));
+            for title in &merged {
+                digest.push_str(&format!("- {}\n", title));
+            }
+        }
+    }
+
+    if let Ok(agents) = crate::agents::get_agent_history(Some(50)) {
+        let runs: Vec<_> = agents
+            .into_iter()
+            .filter(|a| a.project == project && a.started_at.as_str() >= start.as_str())
+            .collect();
+        if !runs.is_empty() {
+            digest.push_str(&format!("\nAgent runs ({}):\n", runs.len()));
+            for run in &runs {
+                digest.push_str(&format!(
+                    "- {} on {}: {}\n",
+                    run.workflow_name,
+                    run.branch,
+                    run.conclusion.as_deref().unwrap_or(&run.status)
+                ));
+            }
+        }
+    }
+
+    if let Ok(recent) = crate::activity::get_activity_events(
+        Some(project.clone()),
+        None,
+        Some(start.clone()),
+        None,
+        None,
+        Some(50),
+    ) {
+        if !recent.is_empty() {
+            digest.push_str(&format!("\nEvents ({}):\n", recent.len()));
+            for event in &recent {
+                digest.push_str(&format!("- {}\n", event.message));
+            }
+        }
+    }
+
+    let summary = if polish.unwrap_or(false) {
+        crate::architect::complete_simple(STANDUP_SYSTEM, &digest).await?
+    } else {
+        digest
+    };
+
+    if speak.unwrap_or(false) {
+        crate::speech::enqueue(summary.clone(), crate::speech::SpeechPriority::Normal);
+    }
+    Ok(summary)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DashboardSnapshot {
+    generated_at: String,
+    projects: Vec<Project>,
+    agents_this_week: Vec<crate::agents::Agent>,
+    time_report: crate::time_tracking::TimeReport,
+    usage: crate::usage::UsageAnalytics,
+    specs: Vec<SnapshotSpecs>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SnapshotSpecs {
+    project: String,
+    specs: Vec<specs::SpecInfo>,
+}
+
+fn build_dashboard_snapshot() -> Result<DashboardSnapshot, String> {
+    let projects = get_projects_blocking()?;
+
+    let week_ago = (chrono::Utc::now() - chrono::Duration::weeks(1)).to_rfc3339();
+    let agents_this_week = crate::agents::get_agent_history(Some(100))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|a| a.started_at.as_str() >= week_ago.as_str())
+        .collect();
+
+    let mut spec_sections = Vec::new();
+    for project in &projects {
+        let infos = specs::list_specs_for_project(Path::new(&project.path)).unwrap_or_default();
+        if !infos.is_empty() {
+            spec_sections.push(SnapshotSpecs {
+                project: project.name.clone(),
+                specs: infos,
+            });
+        }
+    }
+
+    Ok(DashboardSnapshot {
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        projects,
+        agents_this_week,
+        time_report: crate::time_tracking::get_time_report("week".to_string())?,
+        usage: crate::usage::get_usage_analytics("week".to_string())?,
+        specs: spec_sections,
+    })
+}
+
+/// Render the snapshot as a self-contained HTML page for sharing.
+fn snapshot_to_html(snapshot: &DashboardSnapshot) -> String {
+    let mut html = String::from(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><title>Sentra Weekly Snapshot</title>\
+         <style>body{font-family:sans-serif;max-width:56rem;margin:2rem auto;padding:0 1rem}\
+         table{border-collapse:collapse;width:100%}td,th{border:1px solid #ccc;padding:4px 8px;\
+         text-align:left}</style></head><body>",
+    );
+    html.push_str(&format!(
+        "<h1>Sentra Weekly Snapshot</h1><p>Generated {}</p>",
+        snapshot.generated_at
+    ));
+
+    html.push_str("<h2>Projects</h2><table><tr><th>Project</th><th>Specs</th><th>Pending</th></tr>");
+    for p in &snapshot.projects {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+            p.name, p.spec_count, p.pending_spec_count
+        ));
+    }
+    html.push_str("</table>");
+
+    html.push_str(&format!(
+        "<h2>Agent Runs This Week ({})</h2><table><tr><th>Project</th><th>Workflow</th>\
+         <th>Branch</th><th>Result</th></tr>",
+        snapshot.agents_this_week.len()
+    ));
+    for a in &snapshot.agents_this_week {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            a.project,
+            a.workflow_name,
+            a.branch,
+            a.conclusion.as_deref().unwrap_or(&a.status)
+        ));
+    }
+    html.push_str("</table>");
+
+    html.push_str("<h2>Time</h2><table><tr><th>Project</th><th>Agent</th><th>Architect</th></tr>");
+    for t in &snapshot.time_report.projects {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}m</td><td>{}m</td></tr>",
+            t.project,
+            t.agent_seconds / 60,
+            t.architect_seconds / 60
+        ));
+    }
+    html.push_str("</table>");
+
+    html.push_str(&format!(
+        "<h2>Usage</h2><p>{} requests, {} input / {} output tokens this week.</p>",
+        snapshot.usage.total_requests,
+        snapshot.usage.total_input_tokens,
+        snapshot.usage.total_output_tokens
+    ));
+
+    html.push_str("</body></html>");
+    html
+}
+
+/// Export a workspace-wide status snapshot (projects, agent runs, time,
+/// usage, spec pipeline) as JSON or a shareable HTML page.
+#[tauri::command]
+pub fn export_dashboard_snapshot(path: String, format: Option<String>) -> Result<(), String> {
+    let snapshot = build_dashboard_snapshot()?;
+    let content = match format.as_deref().unwrap_or("json") {
+        "json" => serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?,
+        "html" => snapshot_to_html(&snapshot),
+        other => return Err(format!("Unknown format \"{}\" (expected json or html)", other)),
+    };
+    fs::write(&path, content).map_err(|e| e.to_string())
+}
+
+const TRUNCATION_MARKER: &str = "\n[... truncated to fit context budget]";
+
+/// Build a context block for the architect. `max_tokens` caps the estimated
+/// size: lower-priority sections are truncated (with a visible marker) or
+/// dropped once the budget runs out.
+#[tauri::command]
+pub fn get_project_context(
+    project_path: String,
+    max_tokens: Option<usize>,
+) -> Result<ProjectContext, String> {
+    let path = Path::new(&project_path);
+    let sections = gather_context_sections(path);
+
+    let budget_chars = max_tokens.map(|t| t * 4);
+    let mut text = String::new();
+    let mut truncated = false;
+
+    for s in sections {
+        let block = format!("# {}\n\n{}\n\n", s.title, s.content.trim_end());
+        match budget_chars {
+            Some(budget) if text.len() >= budget => {
+                truncated = true;
+                break;
+            }
+            Some(budget) if text.len() + block.len() > budget => {
+                let available = budget.saturating_sub(text.len());
+                // Cut on a char boundary, then mark the cut.
+                let mut cut = available.min(block.len());
+                while cut > 0 && !block.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                text.push_str(&block[..cut]);
+                text.push_str(TRUNCATION_MARKER);
+                text.push_str("\n\n");
+                truncated = true;
+            }
+            _ => text.push_str(&block),
+        }
+    }
+
+    let estimated_tokens = estimate_tokens(&text);
+    Ok(ProjectContext {
+        text,
+        estimated_tokens,
+        truncated,
+    })
+}
+
+/// Concatenated contents of the project's `.claude/memory/` files.
+#[tauri::command]
+pub fn get_project_memory(project_path: String) -> Result<String, String> {
+    Ok(crate::memory::read_all(Path::new(&project_path)))
+}
+
+/// Open a GitHub issue in the project's repository, natively when a token
+/// is configured and via the gh CLI otherwise. Returns the issue URL.
+#[tauri::command]
+pub async fn create_github_issue(
+    project_path: String,
+    title: String,
+    body: String,
+    labels: Option<Vec<String>>,
+) -> Result<String, String> {
+    if let Some(provider) = crate::git_provider::non_github() {
+        let remote = crate::git::run_git(Path::new(&project_path), &["remote", "get-url", "origin"])?;
+        let slug = crate::git_provider::slug_from_remote_url(&remote)
+            .ok_or_else(|| format!("Cannot determine repository from remote: {}", remote.trim()))?;
+        return provider
+            .create_issue(&slug, &title, &body, &labels.unwrap_or_default())
+            .await;
+    }
+    if crate::github::token().is_some() {
+        let slug = crate::github::repo_slug(Path::new(&project_path))?;
+        let payload = serde_json::json!({
+            "title": title,
+            "body": body,
+            "labels": labels.unwrap_or_default(),
+        });
+        let issue = crate::github::post_json(&format!("/repos/{}/issues", slug), &payload).await?;
+        return issue
+            .get("html_url")
+            .and_then(|u| u.as_str())
+            .map(String::from)
+            .ok_or_else(|| "GitHub issue response had no URL".to_string());
+    }
+
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let mut cmd = Command::new("gh");
+    cmd.args(["issue", "create", "--title", &title, "--body", &body])
+        .current_dir(&project_path);
+    if let Some(labels) = labels {
+        for label in labels {
+            cmd.args(["--label", &label]);
+        }
+    }
+    let output = cmd.output().map_err(|e| format!("Failed to run gh: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
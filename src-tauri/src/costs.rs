@@ -0,0 +1,219 @@
+//! Cost reporting.
+//!
+//! Turns the token usage ledger into USD estimates using a built-in price
+//! table, aggregated per day and per month. This replaces the old frontend
+//! localStorage cost tracking, which evaporated on reinstall. Prices are
+//! estimates — they track list pricing at the time of writing and exist for
+//! budgeting, not billing.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::notifications::{self, NotificationType};
+use crate::settings;
+use crate::time_tracking;
+use crate::usage::{self, UsageEntry, UsageKind};
+
+/// (input, output) USD per million tokens for a ledger entry. TTS records
+/// characters as input tokens, priced per character accordingly.
+fn price_per_million(entry: &UsageEntry) -> (f64, f64) {
+    let model = entry.model.as_str();
+    if model.contains("haiku") {
+        (0.80, 4.00)
+    } else if model.contains("sonnet") {
+        (3.00, 15.00)
+    } else if model.contains("opus") {
+        (15.00, 75.00)
+    } else if entry.kind == UsageKind::Tts {
+        (15.00, 0.0)
+    } else if entry.kind == UsageKind::Realtime {
+        // Blended text/audio estimate for gpt-4o-realtime.
+        (40.00, 80.00)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// USD estimate for one ledger entry.
+fn usd_for(entry: &UsageEntry) -> f64 {
+    let (input, output) = price_per_million(entry);
+    (entry.input_tokens as f64 * input + entry.output_tokens as f64 * output) / 1_000_000.0
+}
+
+/// One day's spend for one provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostBucket {
+    pub day: String,
+    pub provider: String,
+    pub amount: f64,
+}
+
+/// One calendar month's total spend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyCost {
+    /// "YYYY-MM".
+    pub month: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostReport {
+    pub range: String,
+    pub daily: Vec<CostBucket>,
+    pub monthly: Vec<MonthlyCost>,
+    pub total_usd: f64,
+}
+
+/// Total estimated spend since a point in time, optionally per project.
+pub fn spend_since(start: DateTime<Utc>, project: Option<&str>) -> Result<f64, String> {
+    let mut total = 0.0;
+    for entry in usage::read_ledger()? {
+        let Ok(recorded) = DateTime::parse_from_rfc3339(&entry.recorded_at) else {
+            continue;
+        };
+        if recorded.with_timezone(&Utc) < start {
+            continue;
+        }
+        if let Some(project) = project {
+            if entry.project.as_deref() != Some(project) {
+                continue;
+            }
+        }
+        total += usd_for(&entry);
+    }
+    Ok(total)
+}
+
+/// Midnight UTC on the first of the current month.
+pub fn current_month_start() -> DateTime<Utc> {
+    let now = Utc::now();
+    Utc.with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetStatus {
+    pub monthly_budget: f64,
+    /// Estimated spend since the first of the month.
+    pub spent_usd: f64,
+    /// Fraction of the budget at which the warning fires.
+    pub alert_threshold: f64,
+    pub warning: bool,
+    pub exceeded: bool,
+}
+
+/// This calendar month's spend against the configured budget.
+pub fn budget_status() -> Result<BudgetStatus, String> {
+    let loaded = settings::load_settings()?;
+    let spent = spend_since(current_month_start(), None)?;
+    let warning_at = loaded.monthly_budget * loaded.budget_alert_threshold;
+    Ok(BudgetStatus {
+        monthly_budget: loaded.monthly_budget,
+        spent_usd: spent,
+        alert_threshold: loaded.budget_alert_threshold,
+        warning: loaded.monthly_budget > 0.0 && spent >= warning_at,
+        exceeded: loaded.monthly_budget > 0.0 && spent >= loaded.monthly_budget,
+    })
+}
+
+#[tauri::command]
+pub fn get_budget_status() -> Result<BudgetStatus, String> {
+    budget_status()
+}
+
+/// Which alerts have already fired this crossing: (warning, exceeded).
+/// Reset when spend drops back under the threshold (new month).
+static ALERTED: Mutex<(bool, bool)> = Mutex::new((false, false));
+
+fn check_budget(app: &AppHandle) {
+    let Ok(status) = budget_status() else { return };
+    let mut alerted = ALERTED.lock().unwrap();
+    if status.exceeded && !alerted.1 {
+        alerted.1 = true;
+        let _ = app.emit("budget-exceeded", status.clone());
+        let mut vars = std::collections::HashMap::new();
+        vars.insert("amount".to_string(), format!("{:.2}", status.spent_usd));
+        let _ = notifications::dispatch(NotificationType::BudgetAlert, &vars);
+    } else if status.warning && !alerted.0 {
+        alerted.0 = true;
+        let _ = app.emit("budget-warning", status.clone());
+        let mut vars = std::collections::HashMap::new();
+        vars.insert("amount".to_string(), format!("{:.2}", status.spent_usd));
+        let _ = notifications::dispatch(NotificationType::BudgetAlert, &vars);
+    } else if !status.warning {
+        *alerted = (false, false);
+    }
+}
+
+/// Watch monthly spend and emit `budget-warning` / `budget-exceeded` events
+/// as thresholds are crossed. Called once from setup.
+pub fn start_budget_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            check_budget(&app);
+            tokio::time::sleep(Duration::from_secs(5 * 60)).await;
+        }
+    });
+}
+
+/// Estimated spend over a range, aggregated per day and per month. A
+/// project name filters to calls made on that project's behalf.
+#[tauri::command]
+pub fn get_costs(project: Option<String>, range: String) -> Result<CostReport, String> {
+    let start = time_tracking::range_start(&range, Utc::now())?;
+    let mut daily: Vec<CostBucket> = Vec::new();
+    let mut monthly: Vec<MonthlyCost> = Vec::new();
+    let mut total = 0.0;
+
+    for entry in usage::read_ledger()? {
+        let Ok(recorded) = DateTime::parse_from_rfc3339(&entry.recorded_at) else {
+            continue;
+        };
+        if recorded.with_timezone(&Utc) < start {
+            continue;
+        }
+        if let Some(project) = project.as_deref() {
+            if entry.project.as_deref() != Some(project) {
+                continue;
+            }
+        }
+        let amount = usd_for(&entry);
+        total += amount;
+
+        let day: String = entry.recorded_at.chars().take(10).collect();
+        let month: String = entry.recorded_at.chars().take(7).collect();
+        match daily
+            .iter_mut()
+            .find(|b| b.day == day && b.provider == entry.provider)
+        {
+            Some(bucket) => bucket.amount += amount,
+            None => daily.push(CostBucket {
+                day,
+                provider: entry.provider.clone(),
+                amount,
+            }),
+        }
+        match monthly.iter_mut().find(|m| m.month == month) {
+            Some(bucket) => bucket.amount += amount,
+            None => monthly.push(MonthlyCost { month, amount }),
+        }
+    }
+
+    daily.sort_by(|a, b| a.day.cmp(&b.day));
+    monthly.sort_by(|a, b| a.month.cmp(&b.month));
+    Ok(CostReport {
+        range,
+        daily,
+        monthly,
+        total_usd: total,
+    })
+}
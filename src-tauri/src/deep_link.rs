@@ -0,0 +1,81 @@
+//! `sentra://` deep link handling.
+//!
+//! URLs like `sentra://project/aidio/spec/user-auth` or
+//! `sentra://pr/owner/repo/123` are parsed into navigation targets and
+//! forwarded to the frontend as `navigate` events, so GitHub comments and
+//! Slack messages can link straight into the app.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum NavigationTarget {
+    /// `sentra://project/<name>` — open a project's detail view.
+    #[serde(rename_all = "camelCase")]
+    Project { name: String },
+    /// `sentra://project/<name>/spec/<id>` — open a specific spec.
+    #[serde(rename_all = "camelCase")]
+    Spec { project: String, spec_id: String },
+    /// `sentra://pr/<owner>/<repo>/<number>` — open the PR review view.
+    #[serde(rename_all = "camelCase")]
+    PullRequest {
+        owner: String,
+        repo: String,
+        number: u64,
+    },
+    /// `sentra://agent/<id>` — open an agent's log stream.
+    #[serde(rename_all = "camelCase")]
+    Agent { id: String },
+}
+
+/// Parse a `sentra://` URL into a navigation target.
+pub fn parse_deep_link(url: &str) -> Result<NavigationTarget, String> {
+    let rest = url
+        .strip_prefix("sentra://")
+        .ok_or_else(|| format!("Not a sentra:// URL: {}", url))?;
+    let segments: Vec<&str> = rest
+        .trim_end_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match segments.as_slice() {
+        ["project", name] => Ok(NavigationTarget::Project {
+            name: (*name).to_string(),
+        }),
+        ["project", name, "spec", spec_id] => Ok(NavigationTarget::Spec {
+            project: (*name).to_string(),
+            spec_id: (*spec_id).to_string(),
+        }),
+        ["pr", owner, repo, number] => {
+            let number = number
+                .parse()
+                .map_err(|_| format!("Invalid PR number in {}", url))?;
+            Ok(NavigationTarget::PullRequest {
+                owner: (*owner).to_string(),
+                repo: (*repo).to_string(),
+                number,
+            })
+        }
+        ["agent", id] => Ok(NavigationTarget::Agent {
+            id: (*id).to_string(),
+        }),
+        _ => Err(format!("Unrecognized sentra:// URL: {}", url)),
+    }
+}
+
+/// Parse a deep link and emit it to the frontend, raising the main window so
+/// the navigation is actually visible.
+pub fn handle_deep_link(app: &AppHandle, url: &str) {
+    match parse_deep_link(url) {
+        Ok(target) => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+            let _ = app.emit("navigate", target);
+        }
+        Err(e) => log::warn!("Ignoring deep link: {}", e),
+    }
+}
@@ -0,0 +1,237 @@
+//! Dependency vulnerability scanning.
+//!
+//! Detects the project's stack and shells out to the matching audit tool
+//! (`npm audit`, `cargo audit`, `pip-audit`), normalizing the output into one
+//! vulnerability shape. Optionally files a GitHub issue so a fix agent can be
+//! dispatched against it.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vulnerability {
+    pub package: String,
+    /// "critical", "high", "moderate", "low" — as reported by the tool.
+    pub severity: String,
+    pub title: String,
+    pub installed_version: Option<String>,
+    pub fixed_version: Option<String>,
+    pub advisory_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyScan {
+    /// Which audit tool ran: "npm", "cargo", or "pip".
+    pub stack: String,
+    pub vulnerabilities: Vec<Vulnerability>,
+    /// URL of the fix issue, when one was created.
+    pub issue_url: Option<String>,
+}
+
+fn run_audit(path: &Path, program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    // Audit tools exit non-zero when vulnerabilities exist; the JSON on
+    // stdout is still the result. Only an empty stdout is a real failure.
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    if stdout.trim().is_empty() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(stdout)
+}
+
+/// Parse `npm audit --json` (v2 format).
+fn parse_npm_audit(json: &str) -> Result<Vec<Vulnerability>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let Some(map) = value.get("vulnerabilities").and_then(|v| v.as_object()) else {
+        return Ok(Vec::new());
+    };
+    let mut vulns = Vec::new();
+    for (name, entry) in map {
+        let severity = entry
+            .get("severity")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+        // "via" mixes advisory objects and plain strings (transitive names);
+        // the objects carry the title and URL.
+        let advisory = entry
+            .get("via")
+            .and_then(|v| v.as_array())
+            .and_then(|a| a.iter().find(|v| v.is_object()));
+        let title = advisory
+            .and_then(|a| a.get("title"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("Vulnerable dependency")
+            .to_string();
+        let advisory_url = advisory
+            .and_then(|a| a.get("url"))
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        let fixed_version = entry
+            .get("fixAvailable")
+            .and_then(|f| f.get("version"))
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        vulns.push(Vulnerability {
+            package: name.clone(),
+            severity,
+            title,
+            installed_version: entry
+                .get("range")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            fixed_version,
+            advisory_url,
+        });
+    }
+    Ok(vulns)
+}
+
+/// Parse `cargo audit --json`.
+fn parse_cargo_audit(json: &str) -> Result<Vec<Vulnerability>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let Some(list) = value
+        .pointer("/vulnerabilities/list")
+        .and_then(|v| v.as_array())
+    else {
+        return Ok(Vec::new());
+    };
+    Ok(list
+        .iter()
+        .map(|entry| Vulnerability {
+            package: entry
+                .pointer("/package/name")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string(),
+            severity: entry
+                .pointer("/advisory/cvss")
+                .and_then(|v| v.as_str())
+                .map(|_| "high")
+                .unwrap_or("unknown")
+                .to_string(),
+            title: entry
+                .pointer("/advisory/title")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Vulnerable dependency")
+                .to_string(),
+            installed_version: entry
+                .pointer("/package/version")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            fixed_version: entry
+                .pointer("/versions/patched/0")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            advisory_url: entry
+                .pointer("/advisory/url")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        })
+        .collect())
+}
+
+/// Parse `pip-audit -f json`.
+fn parse_pip_audit(json: &str) -> Result<Vec<Vulnerability>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let Some(deps) = value.get("dependencies").and_then(|v| v.as_array()) else {
+        return Ok(Vec::new());
+    };
+    let mut vulns = Vec::new();
+    for dep in deps {
+        let package = dep
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+        let version = dep.get("version").and_then(|v| v.as_str()).map(String::from);
+        let dep_vulns = dep
+            .get("vulns")
+            .and_then(|v| v.as_array())
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+        for vuln in dep_vulns {
+            vulns.push(Vulnerability {
+                package: package.clone(),
+                severity: "unknown".to_string(),
+                title: vuln
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Vulnerable dependency")
+                    .to_string(),
+                installed_version: version.clone(),
+                fixed_version: vuln
+                    .pointer("/fix_versions/0")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                advisory_url: None,
+            });
+        }
+    }
+    Ok(vulns)
+}
+
+/// Scan the project's dependencies with the stack's audit tool. With
+/// `create_issue`, a GitHub issue summarizing the findings is filed so a fix
+/// agent can pick it up.
+#[tauri::command]
+pub async fn scan_project_dependencies(
+    project_path: String,
+    create_issue: Option<bool>,
+) -> Result<DependencyScan, String> {
+    let path = Path::new(&project_path);
+
+    let (stack, vulnerabilities) = if path.join("package.json").exists() {
+        let json = run_audit(path, "npm", &["audit", "--json"])?;
+        ("npm", parse_npm_audit(&json)?)
+    } else if path.join("Cargo.toml").exists() {
+        let json = run_audit(path, "cargo", &["audit", "--json"])?;
+        ("cargo", parse_cargo_audit(&json)?)
+    } else if path.join("pyproject.toml").exists() || path.join("requirements.txt").exists() {
+        let json = run_audit(path, "pip-audit", &["-f", "json"])?;
+        ("pip", parse_pip_audit(&json)?)
+    } else {
+        return Err("No supported dependency manifest found".to_string());
+    };
+
+    let mut issue_url = None;
+    if create_issue.unwrap_or(false) && !vulnerabilities.is_empty() {
+        let mut body = String::from("Automated dependency scan found:\n\n");
+        for vuln in &vulnerabilities {
+            body.push_str(&format!(
+                "- **{}** ({}): {}{}\n",
+                vuln.package,
+                vuln.severity,
+                vuln.title,
+                vuln.fixed_version
+                    .as_deref()
+                    .map(|v| format!(" — fixed in {}", v))
+                    .unwrap_or_default()
+            ));
+        }
+        body.push_str("\nUpdate the affected dependencies and verify the test suite passes.");
+        let url = crate::commands::create_github_issue(
+            project_path.clone(),
+            format!("Fix {} dependency vulnerabilities", vulnerabilities.len()),
+            body,
+            Some(vec!["security".to_string(), "dependencies".to_string()]),
+        )
+        .await?;
+        issue_url = Some(url);
+    }
+
+    Ok(DependencyScan {
+        stack: stack.to_string(),
+        vulnerabilities,
+        issue_url,
+    })
+}
@@ -0,0 +1,66 @@
+//! Scoped event emission.
+//!
+//! High-volume events (agent log chunks, check output) used to broadcast to
+//! every window, so the menubar window deserialized log payloads it never
+//! displays. Windows now register interest per event name; scoped emitters
+//! target only subscribed windows and fall back to a global broadcast when
+//! nothing has subscribed, which keeps older frontends working.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// Event name -> labels of windows that want it.
+static SUBSCRIPTIONS: Mutex<Option<HashMap<String, HashSet<String>>>> = Mutex::new(None);
+
+/// Emit an event to its subscribed windows, or to every window when no
+/// window has registered for it.
+pub fn emit_scoped<T: Serialize + Clone>(app: &AppHandle, event: &str, payload: T) {
+    let labels: Option<Vec<String>> = SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|map| map.get(event))
+        .filter(|labels| !labels.is_empty())
+        .map(|labels| labels.iter().cloned().collect());
+    match labels {
+        Some(labels) => {
+            for label in labels {
+                let _ = app.emit_to(&label, event, payload.clone());
+            }
+        }
+        None => {
+            let _ = app.emit(event, payload);
+        }
+    }
+}
+
+/// Register the calling window for a scoped event.
+#[tauri::command]
+pub fn subscribe_window_event(window: tauri::Window, event: String) -> Result<(), String> {
+    SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .entry(event)
+        .or_default()
+        .insert(window.label().to_string());
+    Ok(())
+}
+
+/// Drop the calling window's registration for a scoped event. Unknown
+/// registrations are not an error.
+#[tauri::command]
+pub fn unsubscribe_window_event(window: tauri::Window, event: String) -> Result<(), String> {
+    if let Some(map) = SUBSCRIPTIONS.lock().unwrap().as_mut() {
+        if let Some(labels) = map.get_mut(&event) {
+            labels.remove(window.label());
+            if labels.is_empty() {
+                map.remove(&event);
+            }
+        }
+    }
+    Ok(())
+}
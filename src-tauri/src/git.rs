@@ -0,0 +1,677 @@
+//! Git read operations for tracked project repositories.
+//!
+//! Everything shells out to the system `git`; Sentra never links libgit2.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+/// Run git in a project directory and return stdout, or stderr as the error.
+pub fn run_git(project_path: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitStatus {
+    pub branch: String,
+    pub ahead: u32,
+    pub behind: u32,
+    pub staged: Vec<String>,
+    pub unstaged: Vec<String>,
+    pub untracked: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffFile {
+    pub path: String,
+    pub additions: u32,
+    pub deletions: u32,
+    /// Old path when git detected a rename.
+    #[serde(default)]
+    pub renamed_from: Option<String>,
+    /// Binary files have no line counts or hunks.
+    #[serde(default)]
+    pub binary: bool,
+    /// This file's section of the patch.
+    #[serde(default)]
+    pub patch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitDiff {
+    pub files: Vec<DiffFile>,
+    /// The full concatenated patch, for copy/export.
+    pub patch: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    pub hash: String,
+    pub author: String,
+    pub date: String,
+    pub message: String,
+}
+
+/// Branch, ahead/behind counts, and working-tree state.
+#[tauri::command]
+pub fn get_git_status(project_path: String) -> Result<GitStatus, String> {
+    let path = Path::new(&project_path);
+    let branch = run_git(path, &["rev-parse", "--abbrev-ref", "HEAD"])?
+        .trim()
+        .to_string();
+
+    let (ahead, behind) = match run_git(
+        path,
+        &["rev-list", "--left-right", "--count", "HEAD...@{upstream}"],
+    ) {
+        Ok(counts) => {
+            let mut parts = counts.split_whitespace();
+            (
+                parts.next().and_then(|s| s.parse().ok()).unwrap_or(0),
+                parts.next().and_then(|s| s.parse().ok()).unwrap_or(0),
+            )
+        }
+        // No upstream configured.
+        Err(_) => (0, 0),
+    };
+
+    let mut staged = Vec::new();
+    let mut unstaged = Vec::new();
+    let mut untracked = Vec::new();
+    for line in run_git(path, &["status", "--porcelain"])?.lines() {
+        if line.len() < 4 {
+            continue;
+        }
+        let (status, file) = line.split_at(3);
+        let file = file.to_string();
+        let mut chars = status.chars();
+        let index = chars.next().unwrap_or(' ');
+        let worktree = chars.next().unwrap_or(' ');
+        if index == '?' {
+            untracked.push(file);
+        } else {
+            if index != ' ' {
+                staged.push(file.clone());
+            }
+            if worktree != ' ' {
+                unstaged.push(file);
+            }
+        }
+    }
+
+    Ok(GitStatus {
+        branch,
+        ahead,
+        behind,
+        staged,
+        unstaged,
+        untracked,
+    })
+}
+
+/// Working-tree diff with per-file hunks, line counts, and rename/binary
+/// detection. `path_filter` limits the diff to one file so the viewer can
+/// load large changesets lazily.
+#[tauri::command]
+pub fn get_git_diff(
+    project_path: String,
+    staged: bool,
+    path_filter: Option<String>,
+) -> Result<GitDiff, String> {
+    let path = Path::new(&project_path);
+    let mut base: Vec<&str> = vec!["diff", "-M"];
+    if staged {
+        base.push("--cached");
+    }
+    let mut pathspec: Vec<&str> = Vec::new();
+    if let Some(filter) = path_filter.as_deref() {
+        pathspec.push("--");
+        pathspec.push(filter);
+    }
+
+    let mut patch_args = base.clone();
+    patch_args.extend_from_slice(&pathspec);
+    let patch = run_git(path, &patch_args)?;
+
+    let mut numstat_args = base.clone();
+    numstat_args.extend_from_slice(&["--numstat", "-z"]);
+    numstat_args.extend_from_slice(&pathspec);
+    let numstat = run_git(path, &numstat_args)?;
+
+    let mut files = parse_numstat(&numstat);
+    // `git diff` emits file sections in the same order as `--numstat` for
+    // identical arguments, so the segments pair up positionally.
+    for (file, segment) in files.iter_mut().zip(split_patch(&patch)) {
+        file.patch = segment;
+    }
+
+    Ok(GitDiff { files, patch })
+}
+
+/// Parse NUL-terminated `--numstat -z` output. Binary files report `-` for
+/// both counts; renames put the old and new paths in separate fields.
+fn parse_numstat(output: &str) -> Vec<DiffFile> {
+    let mut files = Vec::new();
+    let mut fields = output.split('\0');
+    while let Some(record) = fields.next() {
+        if record.is_empty() {
+            continue;
+        }
+        let mut parts = record.splitn(3, '\t');
+        let additions = parts.next().unwrap_or("").trim();
+        let deletions = parts.next().unwrap_or("").trim();
+        let inline_path = parts.next().unwrap_or("");
+        let binary = additions == "-";
+
+        // A rename leaves the path field empty; the old and new names
+        // follow as their own NUL-terminated fields.
+        let (renamed_from, file_path) = if inline_path.is_empty() {
+            let old = fields.next().unwrap_or("").to_string();
+            let new = fields.next().unwrap_or("").to_string();
+            (Some(old), new)
+        } else {
+            (None, inline_path.to_string())
+        };
+        if file_path.is_empty() {
+            continue;
+        }
+
+        files.push(DiffFile {
+            path: file_path,
+            additions: additions.parse().unwrap_or(0),
+            deletions: deletions.parse().unwrap_or(0),
+            renamed_from,
+            binary,
+            patch: String::new(),
+        });
+    }
+    files
+}
+
+/// Split a combined patch into one segment per `diff --git` header.
+fn split_patch(patch: &str) -> Vec<String> {
+    let mut segments: Vec<String> = Vec::new();
+    for line in patch.lines() {
+        if line.starts_with("diff --git ") {
+            segments.push(String::new());
+        }
+        if let Some(segment) = segments.last_mut() {
+            segment.push_str(line);
+            segment.push('\n');
+        }
+    }
+    segments
+}
+
+const COMMIT_MESSAGE_SYSTEM: &str = "You write git commit messages. Given a \
+staged diff, return ONLY the commit message: a conventional-commit subject \
+line (type(scope): description, imperative, under 72 characters), optionally \
+followed by a blank line and a short body explaining why. No markdown fences, \
+no commentary.";
+
+/// Large staged diffs are summarized from the head; the stat section at the
+/// top carries the overall shape.
+const MAX_COMMIT_DIFF_BYTES: usize = 40_000;
+
+/// Draft a conventional-commit message from the staged diff. The result is
+/// returned for user confirmation, not committed.
+#[tauri::command]
+pub async fn generate_commit_message(project_path: String) -> Result<String, String> {
+    let path = Path::new(&project_path);
+    let stat = run_git(path, &["diff", "--cached", "--stat"])?;
+    let patch = run_git(path, &["diff", "--cached"])?;
+    if patch.trim().is_empty() {
+        return Err("Nothing staged to describe".to_string());
+    }
+
+    let mut input = format!("{}\n{}", stat, patch);
+    if input.len() > MAX_COMMIT_DIFF_BYTES {
+        let mut cut = MAX_COMMIT_DIFF_BYTES;
+        while cut > 0 && !input.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        input.truncate(cut);
+        input.push_str("\n[... diff truncated]");
+    }
+
+    let message = crate::architect::complete_simple(COMMIT_MESSAGE_SYSTEM, &input).await?;
+    Ok(message.trim().trim_matches('`').trim().to_string())
+}
+
+/// Recent commits, newest first. `skip` pages further back, `author` and
+/// `since`/`until` (any format `git log` accepts, e.g. "2 weeks ago" or an
+/// ISO date) filter the range, and `path` limits history to one file.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn get_git_log(
+    project_path: String,
+    limit: Option<u32>,
+    skip: Option<u32>,
+    author: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    path: Option<String>,
+) -> Result<Vec<CommitInfo>, String> {
+    let root = Path::new(&project_path);
+    let limit = format!("-{}", limit.unwrap_or(50));
+    let mut args: Vec<String> = vec![
+        "log".to_string(),
+        limit,
+        "--pretty=format:%H%x1f%an%x1f%aI%x1f%s".to_string(),
+    ];
+    if let Some(skip) = skip {
+        args.push(format!("--skip={}", skip));
+    }
+    if let Some(author) = author.filter(|a| !a.is_empty()) {
+        args.push(format!("--author={}", author));
+    }
+    if let Some(since) = since.filter(|s| !s.is_empty()) {
+        args.push(format!("--since={}", since));
+    }
+    if let Some(until) = until.filter(|u| !u.is_empty()) {
+        args.push(format!("--until={}", until));
+    }
+    if let Some(file) = path.filter(|p| !p.is_empty()) {
+        // --follow keeps a file's history across renames.
+        args.push("--follow".to_string());
+        args.push("--".to_string());
+        args.push(file);
+    }
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = run_git(root, &args)?;
+    Ok(parse_log(&output))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlameLine {
+    /// 1-based line number in the blamed revision.
+    pub line: u32,
+    pub hash: String,
+    pub author: String,
+    pub date: String,
+    pub content: String,
+}
+
+/// Per-line authorship for a file, so the code viewer can answer which
+/// commit (and therefore which agent run) introduced a line. `rev` blames
+/// a specific revision; the default is the working tree.
+#[tauri::command]
+pub fn get_git_blame(
+    project_path: String,
+    file: String,
+    rev: Option<String>,
+) -> Result<Vec<BlameLine>, String> {
+    let root = Path::new(&project_path);
+    let rev = rev.filter(|r| !r.is_empty());
+    let mut args: Vec<&str> = vec!["blame", "--line-porcelain"];
+    if let Some(rev) = rev.as_deref() {
+        args.push(rev);
+    }
+    args.push("--");
+    args.push(&file);
+    let output = run_git(root, &args)?;
+    Ok(parse_blame(&output))
+}
+
+/// Parse `blame --line-porcelain` output: a hash header and full metadata
+/// block per line, followed by the tab-prefixed line content.
+pub fn parse_blame(output: &str) -> Vec<BlameLine> {
+    let mut lines = Vec::new();
+    let mut hash = String::new();
+    let mut line_no = 0u32;
+    let mut author = String::new();
+    let mut date = String::new();
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            lines.push(BlameLine {
+                line: line_no,
+                hash: hash.clone(),
+                author: author.clone(),
+                date: date.clone(),
+                content: content.to_string(),
+            });
+        } else if let Some(name) = line.strip_prefix("author ") {
+            author = name.to_string();
+        } else if let Some(epoch) = line.strip_prefix("author-time ") {
+            date = epoch
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_default();
+        } else {
+            // Header lines look like "<40-hex-hash> <orig> <final> [count]".
+            let mut parts = line.split_whitespace();
+            if let Some(first) = parts.next() {
+                if first.len() == 40 && first.chars().all(|c| c.is_ascii_hexdigit()) {
+                    if let Some(final_line) = parts.nth(1).and_then(|n| n.parse().ok()) {
+                        hash = first.to_string();
+                        line_no = final_line;
+                    }
+                }
+            }
+        }
+    }
+    lines
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleBranch {
+    pub name: String,
+    pub last_commit_date: String,
+    pub last_author: String,
+    pub age_days: i64,
+    /// Whether the branch is fully merged into the current HEAD.
+    pub merged: bool,
+}
+
+/// Local branches that are merged or untouched for `days` (default 30),
+/// excluding the current branch and main/master. Agent branches accumulate
+/// fast; this feeds the cleanup review in the dashboard.
+#[tauri::command]
+pub fn list_stale_branches(
+    project_path: String,
+    days: Option<i64>,
+) -> Result<Vec<StaleBranch>, String> {
+    let path = Path::new(&project_path);
+    let threshold = days.unwrap_or(30);
+
+    let merged: Vec<String> = run_git(path, &["branch", "--merged", "HEAD", "--format=%(refname:short)"])?
+        .lines()
+        .map(|l| l.trim().to_string())
+        .collect();
+    let current = run_git(path, &["rev-parse", "--abbrev-ref", "HEAD"])?
+        .trim()
+        .to_string();
+
+    let output = run_git(
+        path,
+        &[
+            "for-each-ref",
+            "refs/heads",
+            "--format=%(refname:short)%x1f%(committerdate:iso-strict)%x1f%(authorname)",
+        ],
+    )?;
+
+    let now = chrono::Utc::now();
+    let mut branches = Vec::new();
+    for line in output.lines() {
+        let mut parts = line.split('\u{1f}');
+        let (Some(name), Some(date), Some(author)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if name == current || name == "main" || name == "master" {
+            continue;
+        }
+        let age_days = chrono::DateTime::parse_from_rfc3339(date)
+            .map(|d| (now - d.with_timezone(&chrono::Utc)).num_days())
+            .unwrap_or(0);
+        let is_merged = merged.iter().any(|m| m == name);
+        if is_merged || age_days >= threshold {
+            branches.push(StaleBranch {
+                name: name.to_string(),
+                last_commit_date: date.to_string(),
+                last_author: author.to_string(),
+                age_days,
+                merged: is_merged,
+            });
+        }
+    }
+    branches.sort_by(|a, b| b.age_days.cmp(&a.age_days));
+    Ok(branches)
+}
+
+/// Delete the given local branches, and their remote counterparts when
+/// `remote` is set. Stops at the first failure.
+#[tauri::command]
+pub fn delete_branches(
+    project_path: String,
+    names: Vec<String>,
+    remote: bool,
+) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    for name in &names {
+        run_git(path, &["branch", "-D", name])?;
+        if remote {
+            // A branch never pushed simply isn't on the remote; not an error.
+            let _ = run_git(path, &["push", "origin", "--delete", name]);
+        }
+    }
+    Ok(())
+}
+
+/// Stage the given paths, or every change when `files` is empty.
+#[tauri::command]
+pub fn git_stage_files(project_path: String, files: Vec<String>) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    if files.is_empty() {
+        run_git(path, &["add", "-A"])?;
+    } else {
+        let mut args = vec!["add", "--"];
+        args.extend(files.iter().map(String::as_str));
+        run_git(path, &args)?;
+    }
+    Ok(())
+}
+
+/// Commit the staged changes. The subject is validated against the project's
+/// commit rules first; returns the new commit hash.
+#[tauri::command]
+pub fn git_commit(project_path: String, message: String) -> Result<String, String> {
+    let path = Path::new(&project_path);
+    let subject = message.lines().next().unwrap_or_default();
+    let problems = validate_commit_subject(subject, &load_commit_rules(path));
+    if !problems.is_empty() {
+        return Err(format!(
+            "Commit message violates the project's rules: {}",
+            problems.join("; ")
+        ));
+    }
+    run_git(path, &["commit", "-m", &message])?;
+    Ok(run_git(path, &["rev-parse", "HEAD"])?.trim().to_string())
+}
+
+/// Push the branch (current one by default) to a remote (origin by default).
+///
+/// SSH remotes authenticate through the user's agent as usual. For HTTPS
+/// GitHub remotes the token from settings is attached per-invocation via an
+/// extra header, so it never lands in the remote URL or on disk.
+#[tauri::command]
+pub fn git_push(
+    project_path: String,
+    remote: Option<String>,
+    branch: Option<String>,
+) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let remote = remote.unwrap_or_else(|| "origin".to_string());
+    let branch = match branch {
+        Some(branch) => branch,
+        None => run_git(path, &["rev-parse", "--abbrev-ref", "HEAD"])?
+            .trim()
+            .to_string(),
+    };
+
+    let remote_url = run_git(path, &["remote", "get-url", &remote]).unwrap_or_default();
+    let token = crate::settings::load_settings()?.github_token;
+    let mut config = Vec::new();
+    if !token.is_empty() && remote_url.trim().starts_with("https://github.com/") {
+        use base64::Engine;
+        let basic = base64::engine::general_purpose::STANDARD
+            .encode(format!("x-access-token:{}", token));
+        config.push(format!(
+            "http.https://github.com/.extraheader=AUTHORIZATION: basic {}",
+            basic
+        ));
+    }
+
+    let mut args: Vec<&str> = Vec::new();
+    for entry in &config {
+        args.push("-c");
+        args.push(entry);
+    }
+    args.extend(["push", "-u", &remote, &branch]);
+    run_git(path, &args)?;
+    Ok(())
+}
+
+/// Per-project conventional-commit rules, read from
+/// `.sentra/commit-rules.json` when present.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitRules {
+    #[serde(default = "default_commit_types")]
+    pub types: Vec<String>,
+    #[serde(default = "default_max_subject_length")]
+    pub max_subject_length: usize,
+    #[serde(default)]
+    pub require_scope: bool,
+}
+
+fn default_commit_types() -> Vec<String> {
+    ["feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_max_subject_length() -> usize {
+    72
+}
+
+impl Default for CommitRules {
+    fn default() -> Self {
+        CommitRules {
+            types: default_commit_types(),
+            max_subject_length: default_max_subject_length(),
+            require_scope: false,
+        }
+    }
+}
+
+fn load_commit_rules(project_path: &Path) -> CommitRules {
+    let path = project_path.join(".sentra").join("commit-rules.json");
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitViolation {
+    pub hash: String,
+    pub subject: String,
+    pub problems: Vec<String>,
+}
+
+/// Check one subject line against the rules; empty result means it passes.
+pub fn validate_commit_subject(subject: &str, rules: &CommitRules) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if subject.len() > rules.max_subject_length {
+        problems.push(format!(
+            "subject is {} characters (max {})",
+            subject.len(),
+            rules.max_subject_length
+        ));
+    }
+
+    // Merge commits are exempt; squash-merge drops them anyway.
+    if subject.starts_with("Merge ") {
+        return Vec::new();
+    }
+
+    let Some((prefix, description)) = subject.split_once(':') else {
+        problems.push("missing \"type: description\" structure".to_string());
+        return problems;
+    };
+
+    let prefix = prefix.trim_end_matches('!');
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((t, rest)) => (t, rest.strip_suffix(')')),
+        None => (prefix, None),
+    };
+
+    if !rules.types.iter().any(|t| t == commit_type) {
+        problems.push(format!("unknown type \"{}\"", commit_type));
+    }
+    if rules.require_scope && scope.map_or(true, |s| s.is_empty()) {
+        problems.push("missing scope".to_string());
+    }
+    if description.trim().is_empty() {
+        problems.push("empty description".to_string());
+    }
+
+    problems
+}
+
+/// Validate commit subjects on a branch against the project's conventional
+/// commit rules. `base` defaults to `main`. Returns only offending commits.
+#[tauri::command]
+pub fn validate_branch_commits(
+    project_path: String,
+    base: Option<String>,
+) -> Result<Vec<CommitViolation>, String> {
+    let path = Path::new(&project_path);
+    let rules = load_commit_rules(path);
+    let base = base.unwrap_or_else(|| "main".to_string());
+
+    let output = run_git(
+        path,
+        &[
+            "log",
+            &format!("{}..HEAD", base),
+            "--pretty=format:%H%x1f%an%x1f%aI%x1f%s",
+        ],
+    )?;
+
+    Ok(parse_log(&output)
+        .into_iter()
+        .filter_map(|commit| {
+            let problems = validate_commit_subject(&commit.message, &rules);
+            if problems.is_empty() {
+                None
+            } else {
+                Some(CommitViolation {
+                    hash: commit.hash,
+                    subject: commit.message,
+                    problems,
+                })
+            }
+        })
+        .collect())
+}
+
+/// Parse `git log` output using the 0x1f field separator format above.
+pub fn parse_log(output: &str) -> Vec<CommitInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\u{1f}');
+            Some(CommitInfo {
+                hash: parts.next()?.to_string(),
+                author: parts.next()?.to_string(),
+                date: parts.next()?.to_string(),
+                message: parts.next()?.to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,532 @@
+//! Git hosting provider abstraction.
+//!
+//! A [`GitProvider`] covers the forge operations Sentra needs — pull/merge
+//! request listing, diffs, merging, issue creation, and pipeline listing —
+//! with GitHub, GitLab, and Gitea implementations. GitHub delegates to the
+//! existing pr.rs paths (native REST or gh CLI); GitLab and Gitea speak
+//! their REST APIs directly with the tokens from settings, so self-managed
+//! instances work without any CLI tooling.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::pr::PullRequest;
+
+/// One CI pipeline / workflow run on a branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineRun {
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    pub branch: String,
+    pub url: String,
+    pub created_at: String,
+}
+
+#[async_trait]
+pub trait GitProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn list_pull_requests(&self, slug: &str) -> Result<Vec<PullRequest>, String>;
+    async fn get_pull_request(&self, slug: &str, number: u64) -> Result<PullRequest, String>;
+    async fn get_pr_diff(&self, slug: &str, number: u64) -> Result<String, String>;
+    /// Squash-merge; providers map this onto their closest equivalent.
+    async fn merge_pull_request(&self, slug: &str, number: u64) -> Result<(), String>;
+    /// Create an issue and return its URL.
+    async fn create_issue(
+        &self,
+        slug: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<String, String>;
+    async fn list_pipelines(&self, slug: &str) -> Result<Vec<PipelineRun>, String>;
+}
+
+fn split_slug(slug: &str) -> Result<(String, String), String> {
+    let mut parts = slug.splitn(2, '/');
+    match (parts.next(), parts.next()) {
+        (Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => {
+            Ok((owner.to_string(), repo.to_string()))
+        }
+        _ => Err(format!("Invalid repository slug: {}", slug)),
+    }
+}
+
+/// The repository path from any forge's clone URL: everything after the
+/// host, with scheme, credentials, and the `.git` suffix stripped. GitLab
+/// subgroup paths ("group/sub/repo") come through intact.
+pub fn slug_from_remote_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let rest = if let Some(rest) = url.split("://").nth(1) {
+        // https://host/owner/repo or ssh://git@host/owner/repo
+        rest.splitn(2, '/').nth(1)?
+    } else {
+        // git@host:owner/repo
+        url.splitn(2, ':').nth(1)?
+    };
+    let slug = rest
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+        .trim_end_matches(".git");
+    if !slug.contains('/') {
+        return None;
+    }
+    Some(slug.to_string())
+}
+
+/// Send an authenticated JSON request to a forge API. All forges share the
+/// GitHub rate-limit bucket, which stays well under every instance's limits.
+async fn forge_request(
+    method: reqwest::Method,
+    url: &str,
+    headers: &[(&str, String)],
+    body: Option<&Value>,
+) -> Result<reqwest::Response, String> {
+    crate::rate_limit::acquire(crate::rate_limit::Provider::GitHub).await;
+    let client = reqwest::Client::new();
+    let mut request = client.request(method, url).header("User-Agent", "sentra");
+    for (name, value) in headers {
+        request = request.header(*name, value);
+    }
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+            .unwrap_or(text);
+        return Err(format!("API error {}: {}", status.as_u16(), message.trim()));
+    }
+    Ok(response)
+}
+
+async fn forge_json(
+    method: reqwest::Method,
+    url: &str,
+    headers: &[(&str, String)],
+    body: Option<&Value>,
+) -> Result<Value, String> {
+    forge_request(method, url, headers, body)
+        .await?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+fn str_at<'a>(value: &'a Value, pointer: &str) -> &'a str {
+    value.pointer(pointer).and_then(|v| v.as_str()).unwrap_or("")
+}
+
+/// GitHub, via the existing pr.rs dual-path (native REST or gh CLI).
+pub struct GitHub;
+
+#[async_trait]
+impl GitProvider for GitHub {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    async fn list_pull_requests(&self, slug: &str) -> Result<Vec<PullRequest>, String> {
+        let (owner, repo) = split_slug(slug)?;
+        crate::pr::get_pull_requests(owner, repo).await
+    }
+
+    async fn get_pull_request(&self, slug: &str, number: u64) -> Result<PullRequest, String> {
+        let (owner, repo) = split_slug(slug)?;
+        crate::pr::get_pull_request(owner, repo, number).await
+    }
+
+    async fn get_pr_diff(&self, slug: &str, number: u64) -> Result<String, String> {
+        let (owner, repo) = split_slug(slug)?;
+        crate::pr::get_pr_diff(owner, repo, number).await
+    }
+
+    async fn merge_pull_request(&self, slug: &str, number: u64) -> Result<(), String> {
+        let (owner, repo) = split_slug(slug)?;
+        crate::pr::merge_pull_request(owner, repo, number, None, None, None)
+            .await
+            .map(|_| ())
+    }
+
+    async fn create_issue(
+        &self,
+        slug: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<String, String> {
+        if crate::github::token().is_some() {
+            let payload = serde_json::json!({ "title": title, "body": body, "labels": labels });
+            let issue =
+                crate::github::post_json(&format!("/repos/{}/issues", slug), &payload).await?;
+            return issue
+                .get("html_url")
+                .and_then(|u| u.as_str())
+                .map(String::from)
+                .ok_or_else(|| "GitHub issue response had no URL".to_string());
+        }
+        let mut args = vec!["issue", "create", "--repo", slug, "--title", title, "--body", body];
+        for label in labels {
+            args.push("--label");
+            args.push(label);
+        }
+        Ok(crate::pr::run_gh(&args)?.trim().to_string())
+    }
+
+    async fn list_pipelines(&self, slug: &str) -> Result<Vec<PipelineRun>, String> {
+        let endpoint = format!("repos/{}/actions/runs?per_page=30", slug);
+        let response = if crate::github::token().is_some() {
+            crate::github::get_json(&format!("/{}", endpoint)).await?
+        } else {
+            crate::pr::run_gh_json(&["api", &endpoint])?
+        };
+        Ok(response
+            .get("workflow_runs")
+            .and_then(|r| r.as_array())
+            .into_iter()
+            .flatten()
+            .map(|run| PipelineRun {
+                id: run.get("id").and_then(|i| i.as_u64()).unwrap_or(0).to_string(),
+                name: str_at(run, "/name").to_string(),
+                status: run
+                    .get("conclusion")
+                    .and_then(|c| c.as_str())
+                    .unwrap_or(str_at(run, "/status"))
+                    .to_string(),
+                branch: str_at(run, "/head_branch").to_string(),
+                url: str_at(run, "/html_url").to_string(),
+                created_at: str_at(run, "/created_at").to_string(),
+            })
+            .collect())
+    }
+}
+
+/// GitLab (gitlab.com or self-managed), via the v4 REST API.
+pub struct GitLab {
+    base_url: String,
+    token: String,
+}
+
+impl GitLab {
+    fn api(&self, path: &str) -> String {
+        format!("{}/api/v4{}", self.base_url.trim_end_matches('/'), path)
+    }
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        vec![("PRIVATE-TOKEN", self.token.clone())]
+    }
+
+    /// GitLab addresses projects by URL-encoded "owner%2Frepo".
+    fn project_id(slug: &str) -> String {
+        slug.replace('/', "%2F")
+    }
+
+    fn convert_mr(mr: &Value) -> PullRequest {
+        PullRequest {
+            number: mr.get("iid").and_then(|i| i.as_u64()).unwrap_or(0),
+            title: str_at(mr, "/title").to_string(),
+            author: str_at(mr, "/author/username").to_string(),
+            branch: str_at(mr, "/source_branch").to_string(),
+            state: match str_at(mr, "/state") {
+                "opened" => "OPEN".to_string(),
+                other => other.to_uppercase(),
+            },
+            checks_status: "none".to_string(),
+            checks: Vec::new(),
+            url: str_at(mr, "/web_url").to_string(),
+            created_at: str_at(mr, "/created_at").to_string(),
+            additions: 0,
+            deletions: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl GitProvider for GitLab {
+    fn name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    async fn list_pull_requests(&self, slug: &str) -> Result<Vec<PullRequest>, String> {
+        let url = self.api(&format!(
+            "/projects/{}/merge_requests?state=opened&per_page=50",
+            Self::project_id(slug)
+        ));
+        let mrs = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(mrs
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(Self::convert_mr)
+            .collect())
+    }
+
+    async fn get_pull_request(&self, slug: &str, number: u64) -> Result<PullRequest, String> {
+        let url = self.api(&format!(
+            "/projects/{}/merge_requests/{}",
+            Self::project_id(slug),
+            number
+        ));
+        let mr = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(Self::convert_mr(&mr))
+    }
+
+    async fn get_pr_diff(&self, slug: &str, number: u64) -> Result<String, String> {
+        let url = self.api(&format!(
+            "/projects/{}/merge_requests/{}/changes",
+            Self::project_id(slug),
+            number
+        ));
+        let changes = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        let mut diff = String::new();
+        for change in changes
+            .get("changes")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+        {
+            // The diff field holds bare hunks; re-add the file header so the
+            // result reads like a normal unified diff.
+            diff.push_str(&format!(
+                "diff --git a/{} b/{}\n",
+                str_at(change, "/old_path"),
+                str_at(change, "/new_path")
+            ));
+            diff.push_str(str_at(change, "/diff"));
+            if !diff.ends_with('\n') {
+                diff.push('\n');
+            }
+        }
+        Ok(diff)
+    }
+
+    async fn merge_pull_request(&self, slug: &str, number: u64) -> Result<(), String> {
+        let url = self.api(&format!(
+            "/projects/{}/merge_requests/{}/merge",
+            Self::project_id(slug),
+            number
+        ));
+        forge_json(
+            reqwest::Method::PUT,
+            &url,
+            &self.headers(),
+            Some(&serde_json::json!({ "squash": true })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn create_issue(
+        &self,
+        slug: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<String, String> {
+        let url = self.api(&format!("/projects/{}/issues", Self::project_id(slug)));
+        let payload = serde_json::json!({
+            "title": title,
+            "description": body,
+            "labels": labels.join(","),
+        });
+        let issue = forge_json(reqwest::Method::POST, &url, &self.headers(), Some(&payload)).await?;
+        let web_url = str_at(&issue, "/web_url");
+        if web_url.is_empty() {
+            return Err("GitLab issue response had no URL".to_string());
+        }
+        Ok(web_url.to_string())
+    }
+
+    async fn list_pipelines(&self, slug: &str) -> Result<Vec<PipelineRun>, String> {
+        let url = self.api(&format!(
+            "/projects/{}/pipelines?per_page=30",
+            Self::project_id(slug)
+        ));
+        let pipelines = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(pipelines
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|p| {
+                let id = p.get("id").and_then(|i| i.as_u64()).unwrap_or(0);
+                let name = str_at(p, "/name");
+                PipelineRun {
+                    id: id.to_string(),
+                    name: if name.is_empty() {
+                        format!("Pipeline #{}", id)
+                    } else {
+                        name.to_string()
+                    },
+                    status: str_at(p, "/status").to_string(),
+                    branch: str_at(p, "/ref").to_string(),
+                    url: str_at(p, "/web_url").to_string(),
+                    created_at: str_at(p, "/created_at").to_string(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Gitea (self-hosted), via the v1 REST API.
+pub struct Gitea {
+    base_url: String,
+    token: String,
+}
+
+impl Gitea {
+    fn api(&self, path: &str) -> String {
+        format!("{}/api/v1{}", self.base_url.trim_end_matches('/'), path)
+    }
+
+    fn headers(&self) -> Vec<(&'static str, String)> {
+        vec![("Authorization", format!("token {}", self.token))]
+    }
+
+    fn convert_pr(pr: &Value) -> PullRequest {
+        PullRequest {
+            number: pr.get("number").and_then(|n| n.as_u64()).unwrap_or(0),
+            title: str_at(pr, "/title").to_string(),
+            author: str_at(pr, "/user/login").to_string(),
+            branch: str_at(pr, "/head/ref").to_string(),
+            state: str_at(pr, "/state").to_uppercase(),
+            checks_status: "none".to_string(),
+            checks: Vec::new(),
+            url: str_at(pr, "/html_url").to_string(),
+            created_at: str_at(pr, "/created_at").to_string(),
+            additions: 0,
+            deletions: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl GitProvider for Gitea {
+    fn name(&self) -> &'static str {
+        "gitea"
+    }
+
+    async fn list_pull_requests(&self, slug: &str) -> Result<Vec<PullRequest>, String> {
+        let url = self.api(&format!("/repos/{}/pulls?state=open&limit=50", slug));
+        let prs = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(prs
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(Self::convert_pr)
+            .collect())
+    }
+
+    async fn get_pull_request(&self, slug: &str, number: u64) -> Result<PullRequest, String> {
+        let url = self.api(&format!("/repos/{}/pulls/{}", slug, number));
+        let pr = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(Self::convert_pr(&pr))
+    }
+
+    async fn get_pr_diff(&self, slug: &str, number: u64) -> Result<String, String> {
+        let url = self.api(&format!("/repos/{}/pulls/{}.diff", slug, number));
+        forge_request(reqwest::Method::GET, &url, &self.headers(), None)
+            .await?
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read diff: {}", e))
+    }
+
+    async fn merge_pull_request(&self, slug: &str, number: u64) -> Result<(), String> {
+        let url = self.api(&format!("/repos/{}/pulls/{}/merge", slug, number));
+        forge_request(
+            reqwest::Method::POST,
+            &url,
+            &self.headers(),
+            Some(&serde_json::json!({ "Do": "squash" })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn create_issue(
+        &self,
+        slug: &str,
+        title: &str,
+        body: &str,
+        _labels: &[String],
+    ) -> Result<String, String> {
+        // Gitea's issue API takes label ids, not names; labels are skipped
+        // rather than requiring an extra lookup round-trip.
+        let url = self.api(&format!("/repos/{}/issues", slug));
+        let payload = serde_json::json!({ "title": title, "body": body });
+        let issue = forge_json(reqwest::Method::POST, &url, &self.headers(), Some(&payload)).await?;
+        let html_url = str_at(&issue, "/html_url");
+        if html_url.is_empty() {
+            return Err("Gitea issue response had no URL".to_string());
+        }
+        Ok(html_url.to_string())
+    }
+
+    async fn list_pipelines(&self, slug: &str) -> Result<Vec<PipelineRun>, String> {
+        let url = self.api(&format!("/repos/{}/actions/tasks", slug));
+        let response = forge_json(reqwest::Method::GET, &url, &self.headers(), None).await?;
+        Ok(response
+            .get("workflow_runs")
+            .and_then(|r| r.as_array())
+            .into_iter()
+            .flatten()
+            .map(|run| PipelineRun {
+                id: run.get("id").and_then(|i| i.as_u64()).unwrap_or(0).to_string(),
+                name: str_at(run, "/name").to_string(),
+                status: str_at(run, "/status").to_string(),
+                branch: str_at(run, "/head_branch").to_string(),
+                url: str_at(run, "/url").to_string(),
+                created_at: str_at(run, "/created_at").to_string(),
+            })
+            .collect())
+    }
+}
+
+/// Provider by settings name; unknown names fall back to GitHub.
+pub fn provider_by_name(name: &str, loaded: &crate::settings::Settings) -> Box<dyn GitProvider> {
+    match name {
+        "gitlab" => Box::new(GitLab {
+            base_url: loaded.gitlab_url.clone(),
+            token: loaded.gitlab_token.clone(),
+        }),
+        "gitea" => Box::new(Gitea {
+            base_url: loaded.gitea_url.clone(),
+            token: loaded.gitea_token.clone(),
+        }),
+        _ => Box::new(GitHub),
+    }
+}
+
+/// The provider selected in settings.
+pub fn provider_from_settings() -> Result<Box<dyn GitProvider>, String> {
+    let loaded = crate::settings::load_settings()?;
+    Ok(provider_by_name(&loaded.git_provider, &loaded))
+}
+
+/// The configured provider when it is not GitHub; pr.rs commands delegate
+/// to it and otherwise keep their GitHub-native paths.
+pub fn non_github() -> Option<Box<dyn GitProvider>> {
+    let loaded = crate::settings::load_settings().ok()?;
+    match loaded.git_provider.as_str() {
+        "gitlab" | "gitea" => Some(provider_by_name(&loaded.git_provider, &loaded)),
+        _ => None,
+    }
+}
+
+/// CI pipeline runs for a repository on the configured provider.
+#[tauri::command]
+pub async fn list_pipeline_runs(owner: String, repo: String) -> Result<Vec<PipelineRun>, String> {
+    let provider = provider_from_settings()?;
+    provider
+        .list_pipelines(&format!("{}/{}", owner, repo))
+        .await
+}
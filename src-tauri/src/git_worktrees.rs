@@ -0,0 +1,125 @@
+//! Git worktree management for parallel agent branches.
+//!
+//! Each local agent gets an isolated worktree of the project repo under
+//! the Sentra data directory, so concurrent agents never fight over one
+//! working directory.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::git::run_git;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeInfo {
+    pub path: String,
+    /// Checked-out branch, or None when the worktree is detached.
+    pub branch: Option<String>,
+    pub head: String,
+    pub locked: bool,
+    /// Git considers the worktree safe to prune (directory is gone).
+    pub prunable: bool,
+}
+
+/// Where worktrees for a project live: one directory per branch under
+/// the Sentra data dir, keyed by the repo's directory name.
+fn worktree_dir(project_path: &Path, branch: &str) -> Result<std::path::PathBuf, String> {
+    let project = project_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Invalid project path".to_string())?;
+    Ok(crate::settings::sentra_dir()?
+        .join("worktrees")
+        .join(project)
+        .join(crate::specs::slugify(branch)))
+}
+
+/// Create (or reuse) a worktree for `branch`, creating the branch from
+/// HEAD when it does not exist yet. Returns the worktree path.
+#[tauri::command]
+pub fn create_worktree(project_path: String, branch: String) -> Result<String, String> {
+    let root = Path::new(&project_path);
+    if branch.trim().is_empty() {
+        return Err("Branch name cannot be empty".to_string());
+    }
+
+    let dir = worktree_dir(root, &branch)?;
+    let dir_str = dir.to_string_lossy().to_string();
+    if dir.join(".git").exists() {
+        return Ok(dir_str);
+    }
+    if let Some(parent) = dir.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let branch_ref = format!("refs/heads/{}", branch);
+    let exists = run_git(root, &["rev-parse", "--verify", "--quiet", &branch_ref]).is_ok();
+    if exists {
+        run_git(root, &["worktree", "add", &dir_str, &branch])?;
+    } else {
+        run_git(root, &["worktree", "add", "-b", &branch, &dir_str])?;
+    }
+    Ok(dir_str)
+}
+
+/// All worktrees attached to the repo, including the main checkout.
+#[tauri::command]
+pub fn list_worktrees(project_path: String) -> Result<Vec<WorktreeInfo>, String> {
+    let root = Path::new(&project_path);
+    let output = run_git(root, &["worktree", "list", "--porcelain"])?;
+    Ok(parse_worktrees(&output))
+}
+
+/// Remove a worktree and prune stale administrative entries. Refuses to
+/// touch the main checkout; git itself rejects dirty worktrees.
+#[tauri::command]
+pub fn remove_worktree(project_path: String, worktree_path: String) -> Result<(), String> {
+    let root = Path::new(&project_path);
+    if Path::new(&worktree_path) == root {
+        return Err("Cannot remove the main working directory".to_string());
+    }
+    run_git(root, &["worktree", "remove", &worktree_path])?;
+    run_git(root, &["worktree", "prune"])?;
+    Ok(())
+}
+
+/// Parse `worktree list --porcelain` output: blank-line-separated blocks
+/// of `worktree <path>` / `HEAD <hash>` / `branch <ref>` attribute lines.
+pub fn parse_worktrees(output: &str) -> Vec<WorktreeInfo> {
+    let mut worktrees = Vec::new();
+    let mut current: Option<WorktreeInfo> = None;
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            if let Some(done) = current.take() {
+                worktrees.push(done);
+            }
+            current = Some(WorktreeInfo {
+                path: path.to_string(),
+                branch: None,
+                head: String::new(),
+                locked: false,
+                prunable: false,
+            });
+        } else if let Some(info) = current.as_mut() {
+            if let Some(head) = line.strip_prefix("HEAD ") {
+                info.head = head.to_string();
+            } else if let Some(branch) = line.strip_prefix("branch ") {
+                info.branch = Some(
+                    branch
+                        .strip_prefix("refs/heads/")
+                        .unwrap_or(branch)
+                        .to_string(),
+                );
+            } else if line == "locked" || line.starts_with("locked ") {
+                info.locked = true;
+            } else if line == "prunable" || line.starts_with("prunable ") {
+                info.prunable = true;
+            }
+        }
+    }
+    if let Some(done) = current.take() {
+        worktrees.push(done);
+    }
+    worktrees
+}
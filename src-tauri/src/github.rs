@@ -0,0 +1,146 @@
+//! Native GitHub REST client.
+//!
+//! Speaks to api.github.com directly with the token from settings, so PR
+//! and issue features work without the gh CLI installed. Callers fall back
+//! to gh when no token is configured.
+
+use std::path::Path;
+
+use reqwest::Method;
+use serde_json::Value;
+
+const API_BASE: &str = "https://api.github.com";
+
+/// The configured GitHub token, if any. Native REST calls are only
+/// attempted when this returns Some; otherwise callers use the gh CLI.
+pub fn token() -> Option<String> {
+    crate::settings::load_settings()
+        .ok()
+        .map(|s| s.github_token)
+        .filter(|t| !t.is_empty())
+}
+
+async fn send(method: Method, path: &str, body: Option<&Value>, accept: &str) -> Result<reqwest::Response, String> {
+    let token = token().ok_or_else(|| "No GitHub token configured".to_string())?;
+    crate::rate_limit::acquire(crate::rate_limit::Provider::GitHub).await;
+
+    let client = reqwest::Client::new();
+    let mut request = client
+        .request(method, format!("{}{}", API_BASE, path))
+        .bearer_auth(token)
+        .header("Accept", accept)
+        .header("User-Agent", "sentra")
+        .header("X-GitHub-Api-Version", "2022-11-28");
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("GitHub request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        // Error bodies carry a human-readable "message" field.
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+            .unwrap_or(text);
+        return Err(format!("GitHub API error {}: {}", status.as_u16(), message.trim()));
+    }
+    Ok(response)
+}
+
+/// GET an endpoint and parse the JSON response.
+pub async fn get_json(path: &str) -> Result<Value, String> {
+    send(Method::GET, path, None, "application/vnd.github+json")
+        .await?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub response: {}", e))
+}
+
+/// GET an endpoint with a custom Accept header, returning the raw body.
+/// Used for diff and raw-content media types.
+pub async fn get_raw(path: &str, accept: &str) -> Result<String, String> {
+    send(Method::GET, path, None, accept)
+        .await?
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read GitHub response: {}", e))
+}
+
+/// POST a JSON body and parse the JSON response.
+pub async fn post_json(path: &str, body: &Value) -> Result<Value, String> {
+    send(Method::POST, path, Some(body), "application/vnd.github+json")
+        .await?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub response: {}", e))
+}
+
+/// POST a JSON body, discarding the response. Some endpoints (workflow
+/// reruns) reply 201 with an empty body that would fail JSON parsing.
+pub async fn post(path: &str, body: &Value) -> Result<(), String> {
+    send(Method::POST, path, Some(body), "application/vnd.github+json").await?;
+    Ok(())
+}
+
+/// PUT a JSON body, discarding the response body.
+pub async fn put(path: &str, body: &Value) -> Result<(), String> {
+    send(Method::PUT, path, Some(body), "application/vnd.github+json").await?;
+    Ok(())
+}
+
+/// PATCH a JSON body and parse the JSON response.
+pub async fn patch_json(path: &str, body: &Value) -> Result<Value, String> {
+    send(Method::PATCH, path, Some(body), "application/vnd.github+json")
+        .await?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub response: {}", e))
+}
+
+/// DELETE an endpoint, discarding the response body.
+pub async fn delete(path: &str) -> Result<(), String> {
+    send(Method::DELETE, path, None, "application/vnd.github+json").await?;
+    Ok(())
+}
+
+/// Run a GraphQL query. GraphQL reports failures in-band with a 200, so
+/// the "errors" array is surfaced as an Err.
+pub async fn graphql(query: &str, variables: Value) -> Result<Value, String> {
+    let body = serde_json::json!({ "query": query, "variables": variables });
+    let response = post_json("/graphql", &body).await?;
+    if let Some(error) = response
+        .pointer("/errors/0/message")
+        .and_then(|m| m.as_str())
+    {
+        return Err(format!("GitHub GraphQL error: {}", error));
+    }
+    Ok(response)
+}
+
+/// The "owner/repo" slug from a project's origin remote.
+pub fn repo_slug(project_path: &Path) -> Result<String, String> {
+    let url = crate::git::run_git(project_path, &["remote", "get-url", "origin"])?;
+    parse_repo_slug(url.trim())
+        .ok_or_else(|| format!("origin is not a GitHub remote: {}", url.trim()))
+}
+
+/// Extract "owner/repo" from the remote URL forms git produces:
+/// https://github.com/o/r(.git), git@github.com:o/r(.git), and
+/// ssh://git@github.com/o/r(.git).
+pub fn parse_repo_slug(url: &str) -> Option<String> {
+    let rest = url
+        .strip_prefix("https://github.com/")
+        .or_else(|| url.strip_prefix("http://github.com/"))
+        .or_else(|| url.strip_prefix("git@github.com:"))
+        .or_else(|| url.strip_prefix("ssh://git@github.com/"))?;
+    let slug = rest.trim_end_matches('/').trim_end_matches(".git");
+    let mut parts = slug.splitn(2, '/');
+    let owner = parts.next().filter(|o| !o.is_empty())?;
+    let repo = parts.next().filter(|r| !r.is_empty() && !r.contains('/'))?;
+    Some(format!("{}/{}", owner, repo))
+}
@@ -0,0 +1,180 @@
+//! GitHub issue management: list, view, comment, close.
+//!
+//! The spec workflow creates issues; these commands let the user track and
+//! triage them without leaving Sentra. Like pr.rs, everything goes through
+//! the native REST client when a token is configured and falls back to the
+//! gh CLI otherwise.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::pr::{run_gh, run_gh_json};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    /// "open" or "closed".
+    pub state: String,
+    pub author: String,
+    pub labels: Vec<String>,
+    pub body: String,
+    pub url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+fn native() -> bool {
+    crate::github::token().is_some()
+}
+
+/// Build an [`Issue`] from either API's JSON shape; the two differ only in
+/// field names, which are probed in REST-then-gh order.
+fn convert_issue(value: &Value) -> Issue {
+    let str_field = |keys: &[&str]| -> String {
+        keys.iter()
+            .find_map(|k| value.get(*k).and_then(|v| v.as_str()))
+            .unwrap_or_default()
+            .to_string()
+    };
+    Issue {
+        number: value.get("number").and_then(|n| n.as_u64()).unwrap_or(0),
+        title: str_field(&["title"]),
+        state: str_field(&["state"]).to_lowercase(),
+        author: value
+            .pointer("/user/login")
+            .or_else(|| value.pointer("/author/login"))
+            .and_then(|l| l.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        labels: value
+            .get("labels")
+            .and_then(|l| l.as_array())
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter_map(|l| l.get("name").and_then(|n| n.as_str()))
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        body: str_field(&["body"]),
+        url: str_field(&["html_url", "url"]),
+        created_at: str_field(&["created_at", "createdAt"]),
+        updated_at: str_field(&["updated_at", "updatedAt"]),
+    }
+}
+
+const ISSUE_FIELDS: &str = "number,title,state,author,labels,body,url,createdAt,updatedAt";
+
+/// Issues for a repository. `state` is "open" (default), "closed", or
+/// "all"; `labels` requires every given label.
+#[tauri::command]
+pub async fn list_issues(
+    owner: String,
+    repo: String,
+    state: Option<String>,
+    labels: Option<Vec<String>>,
+) -> Result<Vec<Issue>, String> {
+    let state = state.filter(|s| !s.is_empty()).unwrap_or_else(|| "open".to_string());
+    let labels = labels.unwrap_or_default();
+
+    if native() {
+        let mut path = format!(
+            "/repos/{}/{}/issues?state={}&per_page=100",
+            owner, repo, state
+        );
+        if !labels.is_empty() {
+            path.push_str(&format!("&labels={}", labels.join(",")));
+        }
+        let response = crate::github::get_json(&path).await?;
+        return Ok(response
+            .as_array()
+            .into_iter()
+            .flatten()
+            // The issues endpoint also returns PRs; drop them.
+            .filter(|i| i.get("pull_request").is_none())
+            .map(convert_issue)
+            .collect());
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let mut args = vec![
+        "issue", "list", "--repo", &repo_arg, "--state", &state, "--json", ISSUE_FIELDS,
+    ];
+    let label_arg = labels.join(",");
+    if !label_arg.is_empty() {
+        args.push("--label");
+        args.push(&label_arg);
+    }
+    let issues: Vec<Value> = run_gh_json(&args)?;
+    Ok(issues.iter().map(convert_issue).collect())
+}
+
+/// A single issue with its full body.
+#[tauri::command]
+pub async fn get_issue(owner: String, repo: String, number: u64) -> Result<Issue, String> {
+    if native() {
+        let path = format!("/repos/{}/{}/issues/{}", owner, repo, number);
+        return Ok(convert_issue(&crate::github::get_json(&path).await?));
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    let issue: Value = run_gh_json(&[
+        "issue", "view", &number_arg, "--repo", &repo_arg, "--json", ISSUE_FIELDS,
+    ])?;
+    Ok(convert_issue(&issue))
+}
+
+/// Leave a comment on an issue. Returns the comment URL.
+#[tauri::command]
+pub async fn comment_on_issue(
+    owner: String,
+    repo: String,
+    number: u64,
+    body: String,
+) -> Result<String, String> {
+    if native() {
+        let path = format!("/repos/{}/{}/issues/{}/comments", owner, repo, number);
+        let comment =
+            crate::github::post_json(&path, &serde_json::json!({ "body": body })).await?;
+        return comment
+            .get("html_url")
+            .and_then(|u| u.as_str())
+            .map(String::from)
+            .ok_or_else(|| "GitHub comment response had no URL".to_string());
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    let output = run_gh(&[
+        "issue", "comment", &number_arg, "--repo", &repo_arg, "--body", &body,
+    ])?;
+    Ok(output.trim().to_string())
+}
+
+/// Close an issue, optionally with a final comment.
+#[tauri::command]
+pub async fn close_issue(
+    owner: String,
+    repo: String,
+    number: u64,
+    comment: Option<String>,
+) -> Result<(), String> {
+    if let Some(comment) = comment.filter(|c| !c.trim().is_empty()) {
+        comment_on_issue(owner.clone(), repo.clone(), number, comment).await?;
+    }
+
+    if native() {
+        let path = format!("/repos/{}/{}/issues/{}", owner, repo, number);
+        crate::github::patch_json(&path, &serde_json::json!({ "state": "closed" })).await?;
+        return Ok(());
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    run_gh(&["issue", "close", &number_arg, "--repo", &repo_arg])?;
+    Ok(())
+}
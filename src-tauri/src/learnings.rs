@@ -0,0 +1,169 @@
+//! Auto-captured learnings from agent runs.
+//!
+//! When an agent completes, its log is mined (via a lightweight Claude call)
+//! for gotchas and patterns worth keeping. Extracted entries land in a
+//! review queue at `~/.claude/sentra/learning-queue.json`; nothing reaches a
+//! project's memory files until the user approves it.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::architect;
+use crate::commands;
+use crate::memory::{self, MemorySection, WriteMode};
+use crate::settings;
+
+const EXTRACTION_SYSTEM: &str = "You extract durable engineering learnings from an \
+AI agent's run log. Return ONLY a JSON array (possibly empty) of objects with \
+\"section\" (\"gotchas\" or \"patterns\") and \"content\" (one concise markdown \
+bullet, starting with \"- \"). Only include genuinely reusable, non-obvious \
+knowledge; ignore routine build output.";
+
+/// Logs can be large; the tail is where completion summaries live.
+const MAX_LOG_BYTES: usize = 24_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingLearning {
+    pub id: String,
+    pub project_path: String,
+    pub project: String,
+    pub section: MemorySection,
+    pub content: String,
+    /// Agent id the learning was extracted from.
+    pub source: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExtractedLearning {
+    section: String,
+    content: String,
+}
+
+fn queue_path() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("learning-queue.json"))
+}
+
+fn read_queue() -> Result<Vec<PendingLearning>, String> {
+    let path = queue_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+fn write_queue(queue: &[PendingLearning]) -> Result<(), String> {
+    let path = queue_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(queue).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+/// Extract learnings from a completed agent's log and queue them for review.
+/// Returns how many entries were queued.
+#[tauri::command]
+pub async fn capture_agent_learnings(
+    project_path: String,
+    agent_id: String,
+) -> Result<usize, String> {
+    let log_path = commands::telemetry_dir()?.join(format!("agent-{}.log", agent_id));
+    let log = fs::read_to_string(&log_path)
+        .map_err(|e| format!("No log for agent {}: {}", agent_id, e))?;
+    let tail = if log.len() > MAX_LOG_BYTES {
+        // Nudge the cut point forward to the next char boundary so the
+        // slice can't split a multi-byte character.
+        let mut cut = log.len() - MAX_LOG_BYTES;
+        while !log.is_char_boundary(cut) {
+            cut += 1;
+        }
+        &log[cut..]
+    } else {
+        &log[..]
+    };
+
+    let response = architect::complete_simple(EXTRACTION_SYSTEM, tail).await?;
+    let extracted = parse_extraction(&response)?;
+    if extracted.is_empty() {
+        return Ok(0);
+    }
+
+    let project = std::path::Path::new(&project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut queue = read_queue()?;
+    let count = extracted.len();
+    for item in extracted {
+        let section = match item.section.as_str() {
+            "patterns" => MemorySection::Patterns,
+            _ => MemorySection::Gotchas,
+        };
+        queue.push(PendingLearning {
+            id: Uuid::new_v4().to_string(),
+            project_path: project_path.clone(),
+            project: project.clone(),
+            section,
+            content: item.content,
+            source: agent_id.clone(),
+            created_at: Utc::now().to_rfc3339(),
+        });
+    }
+    write_queue(&queue)?;
+    Ok(count)
+}
+
+/// Parse the model's response, tolerating markdown fences around the JSON.
+fn parse_extraction(response: &str) -> Result<Vec<ExtractedLearning>, String> {
+    let trimmed = response.trim();
+    let json = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .map(|s| s.trim_end_matches("```").trim())
+        .unwrap_or(trimmed);
+    serde_json::from_str(json).map_err(|e| format!("Unparseable extraction response: {}", e))
+}
+
+/// Learnings awaiting review.
+#[tauri::command]
+pub fn get_learning_queue() -> Result<Vec<PendingLearning>, String> {
+    read_queue()
+}
+
+/// Approve a queued learning: append it to the project's memory and drop it
+/// from the queue.
+#[tauri::command]
+pub fn approve_learning(id: String) -> Result<(), String> {
+    let mut queue = read_queue()?;
+    let index = queue
+        .iter()
+        .position(|l| l.id == id)
+        .ok_or_else(|| format!("Unknown learning: {}", id))?;
+    let learning = queue.remove(index);
+    memory::write_section(
+        std::path::Path::new(&learning.project_path),
+        learning.section,
+        &learning.content,
+        WriteMode::Append,
+    )?;
+    write_queue(&queue)
+}
+
+/// Discard a queued learning without committing it.
+#[tauri::command]
+pub fn reject_learning(id: String) -> Result<(), String> {
+    let mut queue = read_queue()?;
+    let before = queue.len();
+    queue.retain(|l| l.id != id);
+    if queue.len() == before {
+        return Err(format!("Unknown learning: {}", id));
+    }
+    write_queue(&queue)
+}
@@ -0,0 +1,240 @@
+//! Sentra desktop backend.
+//!
+//! The Rust side of Sentra: project tracking, spec management, agent
+//! monitoring, architect AI integration, and voice notifications. Every
+//! frontend interaction goes through the Tauri commands registered here.
+
+pub mod activity;
+pub mod agent_runner;
+pub mod agent_stream;
+pub mod agents;
+pub mod architect;
+pub mod board;
+pub mod checks;
+pub mod commands;
+pub mod costs;
+pub mod deep_link;
+pub mod events;
+pub mod dependencies;
+pub mod git;
+pub mod git_provider;
+pub mod git_worktrees;
+pub mod github;
+pub mod issues;
+pub mod learnings;
+pub mod lifecycle;
+pub mod llm;
+pub mod logging;
+pub mod mcp;
+pub mod memory;
+pub mod notifications;
+pub mod onboarding;
+pub mod performance;
+pub mod pr;
+pub mod rate_limit;
+pub mod realtime_proxy;
+pub mod secrets;
+pub mod session;
+pub mod settings;
+pub mod specs;
+pub mod speech;
+pub mod storage;
+pub mod templates;
+pub mod time_tracking;
+pub mod tray;
+pub mod tts;
+pub mod usage;
+pub mod watcher;
+pub mod webhooks;
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_deep_link::init())
+        .manage(lifecycle::Lifecycle::new())
+        .setup(|app| {
+            logging::init();
+            if let Err(e) = secrets::migrate_plaintext_settings() {
+                log::warn!("Credential migration failed: {}", e);
+            }
+            performance::mark_started();
+            speech::init(app.handle().clone());
+            tray::setup_tray(app.handle())?;
+
+            // sentra:// URLs from GitHub comments, Slack, etc.
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    for url in event.urls() {
+                        deep_link::handle_deep_link(&handle, url.as_str());
+                    }
+                });
+            }
+            use tauri::Manager;
+            let lifecycle = app.state::<lifecycle::Lifecycle>();
+            lifecycle.start_watcher(app.handle().clone());
+            activity::start_commit_poller(app.handle().clone());
+            agents::start_agent_poller(app.handle().clone());
+            notifications::start_quiet_hours_flusher();
+            costs::start_budget_watcher(app.handle().clone());
+
+            // The realtime voice proxy only runs when an OpenAI key is
+            // configured at launch.
+            let loaded = settings::load_settings().unwrap_or_default();
+            // Likewise the webhook listener only runs when a port is
+            // configured.
+            if loaded.webhook_port > 0 {
+                tauri::async_runtime::spawn(webhooks::start_webhook_listener(
+                    app.handle().clone(),
+                    loaded.webhook_port,
+                    loaded.webhook_secret.clone(),
+                ));
+            }
+            if !loaded.openai_api_key.is_empty() {
+                lifecycle.start_proxy(loaded.openai_api_key.clone());
+            }
+
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            commands::get_projects,
+            commands::create_project,
+            commands::import_project,
+            commands::remove_project,
+            commands::archive_project,
+            commands::get_dashboard_stats,
+            commands::export_dashboard_snapshot,
+            costs::get_costs,
+            costs::get_budget_status,
+            commands::set_project_muted,
+            commands::stop_agent,
+            commands::get_project_context,
+            commands::get_project_context_sections,
+            commands::get_project_memory,
+            commands::generate_standup,
+            memory::update_project_memory,
+            memory::search_memory,
+            memory::list_memory_entries,
+            memory::add_memory_entry,
+            memory::edit_memory_entry,
+            memory::delete_memory_entry,
+            memory::get_memory_history,
+            memory::get_global_memory,
+            memory::update_global_memory,
+            learnings::capture_agent_learnings,
+            learnings::get_learning_queue,
+            learnings::approve_learning,
+            learnings::reject_learning,
+            commands::create_github_issue,
+            issues::list_issues,
+            issues::get_issue,
+            issues::comment_on_issue,
+            issues::close_issue,
+            dependencies::scan_project_dependencies,
+            session::get_session_state,
+            session::save_session_state,
+            settings::get_settings,
+            settings::save_settings,
+            settings::validate_settings,
+            settings::list_profiles,
+            settings::switch_profile,
+            settings::active_profile,
+            secrets::get_secret,
+            secrets::set_secret,
+            settings::speak_notification,
+            settings::list_audio_output_devices,
+            speech::stop_speaking,
+            tts::list_tts_voices,
+            notifications::notify,
+            architect::chat_with_architect,
+            architect::transcribe_audio,
+            architect::list_conversations,
+            architect::get_conversation,
+            architect::delete_conversation,
+            specs::list_specs,
+            specs::reindex_specs,
+            specs::get_spec,
+            specs::save_spec,
+            specs::approve_spec,
+            specs::set_spec_status,
+            specs::decompose_spec,
+            specs::create_github_issue_batch,
+            specs::create_issues_from_spec,
+            specs::sync_spec_issues,
+            specs::lint_spec,
+            specs::get_spec_template,
+            specs::diff_spec_versions,
+            specs::refine_spec,
+            specs::apply_spec_refinement,
+            specs::delete_spec,
+            git::get_git_status,
+            git::get_git_diff,
+            git::get_git_log,
+            git::get_git_blame,
+            git::generate_commit_message,
+            git::git_stage_files,
+            git::git_commit,
+            git::git_push,
+            git::list_stale_branches,
+            git::delete_branches,
+            git::validate_branch_commits,
+            git_worktrees::create_worktree,
+            git_worktrees::list_worktrees,
+            git_worktrees::remove_worktree,
+            pr::get_pull_requests,
+            pr::get_pull_request,
+            pr::get_pr_diff,
+            pr::ai_review_pull_request,
+            pr::merge_pull_request,
+            pr::rerun_failed_checks,
+            pr::request_reviewers,
+            pr::suggest_reviewers,
+            pr::generate_changelog,
+            git_provider::list_pipeline_runs,
+            agents::get_active_agents,
+            agents::get_agent_history,
+            agents::get_workflow_run_details,
+            agents::get_agent_workdir_diff,
+            agent_runner::start_local_agent,
+            agent_runner::kill_local_agent,
+            agent_runner::list_local_agents,
+            agent_stream::start_agent_stream,
+            agent_stream::stop_agent_stream,
+            agent_stream::get_active_streams,
+            events::subscribe_window_event,
+            events::unsubscribe_window_event,
+            checks::run_project_checks,
+            activity::get_activity_events,
+            activity::add_activity_event,
+            activity::export_activity_events,
+            templates::get_templates_command,
+            templates::fetch_remote_template,
+            performance::get_performance_metrics,
+            rate_limit::get_rate_limit_status,
+            lifecycle::restart_watcher,
+            lifecycle::restart_realtime_proxy,
+            lifecycle::start_realtime_proxy_command,
+            lifecycle::stop_realtime_proxy_command,
+            lifecycle::get_realtime_proxy_status,
+            realtime_proxy::get_realtime_proxy_token,
+            time_tracking::get_time_report,
+            usage::get_usage_analytics,
+            storage::get_storage_report,
+            storage::clean_storage_category,
+            onboarding::get_onboarding_status,
+            onboarding::complete_onboarding_step,
+            logging::get_app_logs,
+            board::get_board,
+            board::move_card,
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                use tauri::Manager;
+                app.state::<lifecycle::Lifecycle>().shutdown();
+            }
+        });
+}
@@ -0,0 +1,145 @@
+//! Subsystem lifecycle.
+//!
+//! Managed state owning handles to the restartable background subsystems
+//! (file watcher, realtime voice proxy) so they can be stopped and
+//! restarted when settings change, and shut down cleanly on exit —
+//! instead of being forgotten or looping until the process dies.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tauri::{AppHandle, Manager};
+
+use crate::{realtime_proxy, settings, watcher};
+
+#[derive(Default)]
+pub struct Lifecycle {
+    watcher_stop: Mutex<Option<Arc<AtomicBool>>>,
+    proxy_task: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
+    proxy_running: Arc<AtomicBool>,
+}
+
+impl Lifecycle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start (or replace) the file watcher.
+    pub fn start_watcher(&self, app: AppHandle) {
+        let stop = Arc::new(AtomicBool::new(false));
+        let previous = self
+            .watcher_stop
+            .lock()
+            .unwrap()
+            .replace(Arc::clone(&stop));
+        if let Some(previous) = previous {
+            previous.store(true, Ordering::Relaxed);
+        }
+        watcher::start_file_watcher(app, stop);
+    }
+
+    pub fn stop_watcher(&self) {
+        if let Some(stop) = self.watcher_stop.lock().unwrap().take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Start (or replace) the realtime voice proxy.
+    pub fn start_proxy(&self, api_key: String) {
+        self.stop_proxy();
+        let running = Arc::clone(&self.proxy_running);
+        let task = tauri::async_runtime::spawn(async move {
+            // Give an aborted predecessor a moment to release the port.
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            running.store(true, Ordering::Relaxed);
+            realtime_proxy::start_realtime_proxy(api_key).await;
+            // Only reached when the proxy gives up (e.g. bind failure).
+            running.store(false, Ordering::Relaxed);
+        });
+        *self.proxy_task.lock().unwrap() = Some(task);
+    }
+
+    pub fn stop_proxy(&self) {
+        if let Some(task) = self.proxy_task.lock().unwrap().take() {
+            task.abort();
+        }
+        self.proxy_running.store(false, Ordering::Relaxed);
+    }
+
+    pub fn proxy_running(&self) -> bool {
+        self.proxy_running.load(Ordering::Relaxed)
+    }
+
+    /// Stop everything. Called from the exit handler.
+    pub fn shutdown(&self) {
+        self.stop_watcher();
+        self.stop_proxy();
+    }
+}
+
+/// Tear down the watcher and start a fresh one, picking up the current
+/// ignore patterns and tracked projects.
+#[tauri::command]
+pub fn restart_watcher(app: AppHandle) -> Result<(), String> {
+    app.state::<Lifecycle>().start_watcher(app.clone());
+    Ok(())
+}
+
+/// Tear down the realtime proxy and start a fresh one with the current
+/// OpenAI key. Errors when no key is configured.
+#[tauri::command]
+pub fn restart_realtime_proxy(app: AppHandle) -> Result<(), String> {
+    let loaded = settings::load_settings()?;
+    let lifecycle = app.state::<Lifecycle>();
+    lifecycle.stop_proxy();
+    if loaded.openai_api_key.is_empty() {
+        return Err("No OpenAI API key configured".to_string());
+    }
+    lifecycle.start_proxy(loaded.openai_api_key);
+    Ok(())
+}
+
+/// Start the proxy on demand (a restart when it's already running).
+#[tauri::command]
+pub fn start_realtime_proxy_command(app: AppHandle) -> Result<(), String> {
+    restart_realtime_proxy(app)
+}
+
+#[tauri::command]
+pub fn stop_realtime_proxy_command(app: AppHandle) -> Result<(), String> {
+    app.state::<Lifecycle>().stop_proxy();
+    Ok(())
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyStatus {
+    pub running: bool,
+    pub addr: String,
+    pub key_configured: bool,
+}
+
+/// Whether the proxy is up, where it listens, and whether a key exists —
+/// enough for the frontend to offer start/stop/fix-your-key.
+#[tauri::command]
+pub fn get_realtime_proxy_status(app: AppHandle) -> Result<ProxyStatus, String> {
+    let key_configured = settings::load_settings()
+        .map(|s| !s.openai_api_key.is_empty())
+        .unwrap_or(false);
+    Ok(ProxyStatus {
+        running: app.state::<Lifecycle>().proxy_running(),
+        addr: realtime_proxy::PROXY_ADDR.to_string(),
+        key_configured,
+    })
+}
+
+/// React to an OpenAI key change from `save_settings`: restart the proxy
+/// with the new key, or stop it when the key was cleared.
+pub fn apply_proxy_key(app: &AppHandle, api_key: String) {
+    let lifecycle = app.state::<Lifecycle>();
+    if api_key.is_empty() {
+        lifecycle.stop_proxy();
+    } else {
+        lifecycle.start_proxy(api_key);
+    }
+}
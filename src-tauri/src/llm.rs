@@ -0,0 +1,364 @@
+//! LLM providers.
+//!
+//! The architect conversation sits behind a provider trait so teams that
+//! can't use Anthropic can run it against OpenAI chat completions or a local
+//! Ollama endpoint instead. The provider is picked in settings and can be
+//! overridden per project via `.sentra/config.yml` (`llm_provider: ollama`).
+
+use async_trait::async_trait;
+
+use crate::architect::ChatMessage;
+use crate::rate_limit;
+use crate::settings::Settings;
+
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Provider key as used in settings ("anthropic", "openai", "ollama").
+    fn name(&self) -> &'static str;
+
+    /// Full-strength model for architect conversations and spec pipelines.
+    fn architect_model(&self) -> String;
+
+    /// Small model for lightweight internal pipelines (summaries,
+    /// extraction).
+    fn simple_model(&self) -> String;
+
+    /// Send a system prompt plus conversation and return the reply text.
+    /// Token usage is recorded in the ledger against `project`.
+    async fn chat(
+        &self,
+        model: &str,
+        max_tokens: u32,
+        system: &str,
+        messages: &[ChatMessage],
+        project: Option<&str>,
+    ) -> Result<String, String>;
+}
+
+pub struct Anthropic {
+    api_key: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+    #[serde(default)]
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AnthropicContent {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AnthropicUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+}
+
+#[async_trait]
+impl LlmProvider for Anthropic {
+    fn name(&self) -> &'static str {
+        "anthropic"
+    }
+
+    fn architect_model(&self) -> String {
+        "claude-sonnet-4-5-20250929".to_string()
+    }
+
+    fn simple_model(&self) -> String {
+        "claude-3-5-haiku-20241022".to_string()
+    }
+
+    async fn chat(
+        &self,
+        model: &str,
+        max_tokens: u32,
+        system: &str,
+        messages: &[ChatMessage],
+        project: Option<&str>,
+    ) -> Result<String, String> {
+        if self.api_key.is_empty() {
+            return Err("Anthropic API key not configured".to_string());
+        }
+        rate_limit::acquire(rate_limit::Provider::Anthropic).await;
+        let response = reqwest::Client::new()
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .json(&serde_json::json!({
+                "model": model,
+                "max_tokens": max_tokens,
+                "system": system,
+                "messages": messages,
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("Anthropic request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("Anthropic API error {}: {}", status, body));
+        }
+        let parsed: AnthropicResponse = response.json().await.map_err(|e| e.to_string())?;
+        if let Some(usage) = &parsed.usage {
+            record_usage(
+                "anthropic",
+                model,
+                usage.input_tokens,
+                usage.output_tokens,
+                project,
+            );
+        }
+        Ok(parsed
+            .content
+            .into_iter()
+            .map(|c| c.text)
+            .collect::<Vec<_>>()
+            .join(""))
+    }
+}
+
+pub struct OpenAiChat {
+    api_key: String,
+    model: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OpenAiChatResponse {
+    choices: Vec<OpenAiChoice>,
+    #[serde(default)]
+    usage: Option<OpenAiUsage>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OpenAiChoice {
+    message: OpenAiMessage,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OpenAiMessage {
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OpenAiUsage {
+    #[serde(default)]
+    prompt_tokens: u64,
+    #[serde(default)]
+    completion_tokens: u64,
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiChat {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    fn architect_model(&self) -> String {
+        self.model.clone()
+    }
+
+    fn simple_model(&self) -> String {
+        "gpt-4o-mini".to_string()
+    }
+
+    async fn chat(
+        &self,
+        model: &str,
+        max_tokens: u32,
+        system: &str,
+        messages: &[ChatMessage],
+        project: Option<&str>,
+    ) -> Result<String, String> {
+        if self.api_key.is_empty() {
+            return Err("OpenAI API key not configured".to_string());
+        }
+        // Chat completions take the system prompt as the first message.
+        let mut all = vec![serde_json::json!({ "role": "system", "content": system })];
+        all.extend(messages.iter().map(|m| serde_json::json!(m)));
+
+        rate_limit::acquire(rate_limit::Provider::OpenAi).await;
+        let response = reqwest::Client::new()
+            .post("https://api.openai.com/v1/chat/completions")
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({
+                "model": model,
+                "max_tokens": max_tokens,
+                "messages": all,
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("OpenAI request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("OpenAI API error {}: {}", status, body));
+        }
+        let parsed: OpenAiChatResponse = response.json().await.map_err(|e| e.to_string())?;
+        if let Some(usage) = &parsed.usage {
+            record_usage(
+                "openai",
+                model,
+                usage.prompt_tokens,
+                usage.completion_tokens,
+                project,
+            );
+        }
+        Ok(parsed
+            .choices
+            .into_iter()
+            .map(|c| c.message.content)
+            .collect::<Vec<_>>()
+            .join(""))
+    }
+}
+
+pub struct Ollama {
+    url: String,
+    model: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OllamaResponse {
+    message: OpenAiMessage,
+    #[serde(default)]
+    prompt_eval_count: u64,
+    #[serde(default)]
+    eval_count: u64,
+}
+
+#[async_trait]
+impl LlmProvider for Ollama {
+    fn name(&self) -> &'static str {
+        "ollama"
+    }
+
+    fn architect_model(&self) -> String {
+        self.model.clone()
+    }
+
+    fn simple_model(&self) -> String {
+        // Local models are free; no reason to downgrade.
+        self.model.clone()
+    }
+
+    async fn chat(
+        &self,
+        model: &str,
+        _max_tokens: u32,
+        system: &str,
+        messages: &[ChatMessage],
+        project: Option<&str>,
+    ) -> Result<String, String> {
+        let mut all = vec![serde_json::json!({ "role": "system", "content": system })];
+        all.extend(messages.iter().map(|m| serde_json::json!(m)));
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/api/chat", self.url.trim_end_matches('/')))
+            .json(&serde_json::json!({
+                "model": model,
+                "messages": all,
+                "stream": false,
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("Ollama request failed (is it running?): {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Ollama API error: {}", response.status()));
+        }
+        let parsed: OllamaResponse = response.json().await.map_err(|e| e.to_string())?;
+        // Tokens are free locally but still count toward usage analytics.
+        record_usage(
+            "ollama",
+            model,
+            parsed.prompt_eval_count,
+            parsed.eval_count,
+            project,
+        );
+        Ok(parsed.message.content)
+    }
+}
+
+/// Raw Anthropic messages call for flows that need more than plain text
+/// (the architect's tool-use loop). Returns the parsed response body.
+pub async fn anthropic_messages(
+    api_key: &str,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    if api_key.is_empty() {
+        return Err("Anthropic API key not configured".to_string());
+    }
+    rate_limit::acquire(rate_limit::Provider::Anthropic).await;
+    let response = reqwest::Client::new()
+        .post("https://api.anthropic.com/v1/messages")
+        .header("x-api-key", api_key)
+        .header("anthropic-version", "2023-06-01")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Anthropic request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Anthropic API error {}: {}", status, body));
+    }
+    response.json().await.map_err(|e| e.to_string())
+}
+
+fn record_usage(provider: &str, model: &str, input: u64, output: u64, project: Option<&str>) {
+    let _ = crate::usage::record(
+        provider,
+        model,
+        crate::usage::UsageKind::Architect,
+        input,
+        output,
+        project,
+    );
+}
+
+/// Build the provider from a name. Unknown values fall back to Anthropic.
+fn provider_by_name(loaded: &Settings, name: &str) -> Box<dyn LlmProvider> {
+    match name {
+        "openai" => Box::new(OpenAiChat {
+            api_key: loaded.openai_api_key.clone(),
+            model: loaded.openai_chat_model.clone(),
+        }),
+        "ollama" => Box::new(Ollama {
+            url: loaded.ollama_url.clone(),
+            model: loaded.ollama_model.clone(),
+        }),
+        _ => Box::new(Anthropic {
+            api_key: loaded.anthropic_api_key.clone(),
+        }),
+    }
+}
+
+/// Build the provider selected in settings.
+pub fn provider_from_settings(loaded: &Settings) -> Box<dyn LlmProvider> {
+    provider_by_name(loaded, &loaded.llm_provider)
+}
+
+/// Build the provider for a project, honoring an `llm_provider` override in
+/// its `.sentra/config.yml`. Accepts a tracked project name or a path.
+pub fn provider_for_project(loaded: &Settings, project: &str) -> Box<dyn LlmProvider> {
+    match project_config_provider(project) {
+        Some(name) => provider_by_name(loaded, &name),
+        None => provider_from_settings(loaded),
+    }
+}
+
+/// Read the `llm_provider` key from a project's `.sentra/config.yml`.
+fn project_config_provider(project: &str) -> Option<String> {
+    let root = crate::commands::resolve_project_path(project).ok()?;
+    crate::commands::project_config_value(&root, "llm_provider")
+}
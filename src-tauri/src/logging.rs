@@ -0,0 +1,164 @@
+//! Structured application logging.
+//!
+//! A `log` facade backend that writes JSON lines to a rotating file under
+//! `~/.claude/sentra/logs/`, replacing the println!/eprintln! calls that
+//! used to scatter diagnostics across stderr. Levels are configurable
+//! globally and per module via settings, and the in-app debug panel reads
+//! the file back through [`get_app_logs`].
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+
+/// Rotate the current file past this size; one rotated generation is kept.
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: String,
+    /// Module path that produced the entry (e.g. `sentra_lib::watcher`).
+    pub target: String,
+    pub message: String,
+}
+
+struct LevelConfig {
+    default: LevelFilter,
+    /// Module prefix -> level, longest prefix wins.
+    per_module: Vec<(String, LevelFilter)>,
+}
+
+static LEVELS: Mutex<Option<LevelConfig>> = Mutex::new(None);
+
+fn parse_level(value: &str) -> LevelFilter {
+    match value.to_ascii_lowercase().as_str() {
+        "off" => LevelFilter::Off,
+        "error" => LevelFilter::Error,
+        "warn" => LevelFilter::Warn,
+        "debug" => LevelFilter::Debug,
+        "trace" => LevelFilter::Trace,
+        _ => LevelFilter::Info,
+    }
+}
+
+/// Re-read level configuration from settings. Called at init and whenever
+/// settings are saved, so level changes apply without a restart.
+pub fn reload_levels() {
+    let loaded = settings::load_settings().unwrap_or_default();
+    let mut per_module: Vec<(String, LevelFilter)> = loaded
+        .log_levels
+        .iter()
+        .map(|(module, level)| (module.clone(), parse_level(level)))
+        .collect();
+    // Longest prefix first so `sentra_lib::pr` beats `sentra_lib`.
+    per_module.sort_by_key(|(module, _)| std::cmp::Reverse(module.len()));
+    *LEVELS.lock().unwrap() = Some(LevelConfig {
+        default: parse_level(&loaded.log_level),
+        per_module,
+    });
+}
+
+fn level_for(target: &str) -> LevelFilter {
+    let levels = LEVELS.lock().unwrap();
+    let Some(config) = levels.as_ref() else {
+        return LevelFilter::Info;
+    };
+    config
+        .per_module
+        .iter()
+        .find(|(module, _)| target.starts_with(module.as_str()))
+        .map(|(_, level)| *level)
+        .unwrap_or(config.default)
+}
+
+fn logs_dir() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("logs"))
+}
+
+fn log_file() -> Result<PathBuf, String> {
+    Ok(logs_dir()?.join("sentra.log"))
+}
+
+fn rotate_if_needed(path: &std::path::Path) {
+    let Ok(meta) = fs::metadata(path) else { return };
+    if meta.len() >= MAX_LOG_BYTES {
+        let _ = fs::rename(path, path.with_extension("log.1"));
+    }
+}
+
+struct JsonLogger;
+
+impl Log for JsonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let entry = LogEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            level: record.level().to_string(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+        let Ok(line) = serde_json::to_string(&entry) else {
+            return;
+        };
+        let Ok(path) = log_file() else { return };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        rotate_if_needed(&path);
+        if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&path) {
+            let _ = writeln!(file, "{}", line);
+        }
+        // Errors stay visible on stderr during development.
+        if record.level() == Level::Error {
+            eprintln!("{}: {}", record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: JsonLogger = JsonLogger;
+
+/// Install the logger. Called once from setup before any subsystem starts.
+pub fn init() {
+    reload_levels();
+    if log::set_logger(&LOGGER).is_ok() {
+        // Per-target filtering happens in `enabled`; let everything through
+        // the global gate.
+        log::set_max_level(LevelFilter::Trace);
+    }
+}
+
+/// The most recent `limit` log entries (default 200), oldest first, for the
+/// in-app debug panel.
+#[tauri::command]
+pub fn get_app_logs(limit: Option<usize>) -> Result<Vec<LogEntry>, String> {
+    let limit = limit.unwrap_or(200);
+    let path = log_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let mut entries: Vec<LogEntry> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    if entries.len() > limit {
+        entries.drain(0..entries.len() - limit);
+    }
+    Ok(entries)
+}
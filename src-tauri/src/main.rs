@@ -0,0 +1,12 @@
+// Prevents additional console window on Windows in release, DO NOT REMOVE!!
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+fn main() {
+    // `sentra --mcp` runs the MCP stdio server instead of the GUI, so Claude
+    // Code agents can query project knowledge without launching the app.
+    if std::env::args().any(|a| a == "--mcp") {
+        sentra_lib::mcp::run_stdio_server();
+        return;
+    }
+    sentra_lib::run()
+}
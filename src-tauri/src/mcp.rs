@@ -0,0 +1,166 @@
+//! MCP (Model Context Protocol) server.
+//!
+//! Exposes Sentra's project knowledge to local Claude Code agents over the
+//! MCP stdio transport. Launched with `sentra --mcp`, so an agent config
+//! entry like `{"command": "sentra", "args": ["--mcp"]}` gives every agent
+//! on the machine access to specs, memory, and git state.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::commands;
+use crate::git;
+use crate::specs;
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Run the stdio JSON-RPC loop until stdin closes.
+pub fn run_stdio_server() {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Ok(request) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if let Some(response) = handle_request(&request) {
+            let _ = writeln!(stdout, "{}", response);
+            let _ = stdout.flush();
+        }
+    }
+}
+
+/// Dispatch a single JSON-RPC request. Notifications return `None`.
+pub fn handle_request(request: &Value) -> Option<Value> {
+    let method = request.get("method")?.as_str()?;
+    let id = request.get("id").cloned();
+    // Requests without an id are notifications and get no response.
+    let id = match (method, id) {
+        (_, Some(id)) => id,
+        ("notifications/initialized", None) => return None,
+        (_, None) => return None,
+    };
+
+    let result = match method {
+        "initialize" => Ok(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "sentra", "version": env!("CARGO_PKG_VERSION") },
+        })),
+        "tools/list" => Ok(json!({ "tools": tool_descriptors() })),
+        "tools/call" => call_tool(request.get("params").unwrap_or(&Value::Null)),
+        "ping" => Ok(json!({})),
+        _ => Err(format!("Method not found: {}", method)),
+    };
+
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(message) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32601, "message": message },
+        }),
+    })
+}
+
+fn tool_descriptors() -> Value {
+    let project_arg = json!({
+        "type": "object",
+        "properties": {
+            "project": { "type": "string", "description": "Tracked project name or absolute path" }
+        },
+        "required": ["project"]
+    });
+    json!([
+        {
+            "name": "list_projects",
+            "description": "List every project tracked by Sentra.",
+            "inputSchema": { "type": "object", "properties": {} }
+        },
+        {
+            "name": "list_specs",
+            "description": "List the specs of a tracked project.",
+            "inputSchema": project_arg
+        },
+        {
+            "name": "get_spec",
+            "description": "Read the latest version of a spec's markdown.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project": { "type": "string" },
+                    "specId": { "type": "string" }
+                },
+                "required": ["project", "specId"]
+            }
+        },
+        {
+            "name": "get_project_memory",
+            "description": "Read a project's memory files (gotchas, patterns, decisions).",
+            "inputSchema": project_arg
+        },
+        {
+            "name": "get_git_status",
+            "description": "Branch, ahead/behind, and working-tree state of a project.",
+            "inputSchema": project_arg
+        },
+    ])
+}
+
+/// Resolve a tool's `project` argument, accepting either a tracked project
+/// name or an absolute path.
+fn resolve_project(arg: &str) -> Result<PathBuf, String> {
+    commands::resolve_project_path(arg)
+}
+
+fn call_tool(params: &Value) -> Result<Value, String> {
+    let name = params
+        .get("name")
+        .and_then(|n| n.as_str())
+        .ok_or("Missing tool name")?;
+    let args = params.get("arguments").cloned().unwrap_or(json!({}));
+    let project_arg = || -> Result<PathBuf, String> {
+        let project = args
+            .get("project")
+            .and_then(|p| p.as_str())
+            .ok_or("Missing project argument")?;
+        resolve_project(project)
+    };
+
+    let text = match name {
+        "list_projects" => {
+            let projects = commands::get_projects_blocking()?;
+            serde_json::to_string_pretty(&projects).map_err(|e| e.to_string())?
+        }
+        "list_specs" => {
+            let path = project_arg()?;
+            let specs = specs::list_specs_for_project(&path)?;
+            serde_json::to_string_pretty(&specs).map_err(|e| e.to_string())?
+        }
+        "get_spec" => {
+            let path = project_arg()?;
+            let spec_id = args
+                .get("specId")
+                .and_then(|s| s.as_str())
+                .ok_or("Missing specId argument")?;
+            specs::get_spec(path.display().to_string(), spec_id.to_string())?
+        }
+        "get_project_memory" => {
+            let path = project_arg()?;
+            commands::get_project_memory(path.display().to_string())?
+        }
+        "get_git_status" => {
+            let path = project_arg()?;
+            let status = git::get_git_status(path.display().to_string())?;
+            serde_json::to_string_pretty(&status).map_err(|e| e.to_string())?
+        }
+        _ => return Err(format!("Unknown tool: {}", name)),
+    };
+
+    Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+}
@@ -0,0 +1,462 @@
+//! Project memory.
+//!
+//! Each project keeps hard-won knowledge in `.claude/memory/` as three
+//! markdown files: gotchas, patterns, and decisions. This module owns reading
+//! and (as of now) writing them; writes are serialized and atomic so a voice
+//! flow and a manual edit can't corrupt a file.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemorySection {
+    Gotchas,
+    Patterns,
+    Decisions,
+}
+
+impl MemorySection {
+    pub const ALL: [MemorySection; 3] = [
+        MemorySection::Gotchas,
+        MemorySection::Patterns,
+        MemorySection::Decisions,
+    ];
+
+    pub fn file_name(self) -> &'static str {
+        match self {
+            MemorySection::Gotchas => "gotchas.md",
+            MemorySection::Patterns => "patterns.md",
+            MemorySection::Decisions => "decisions.md",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteMode {
+    Append,
+    Replace,
+}
+
+/// Serializes all memory writes in this process.
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn memory_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".claude").join("memory")
+}
+
+/// Machine-level memory shared by every project (commit style, testing
+/// standards, other conventions).
+pub fn global_memory_dir() -> Result<PathBuf, String> {
+    Ok(crate::settings::sentra_dir()?.join("memory"))
+}
+
+pub fn section_path(project_path: &Path, section: MemorySection) -> PathBuf {
+    memory_dir(project_path).join(section.file_name())
+}
+
+/// Read one section's markdown; missing files read as empty.
+pub fn read_section(project_path: &Path, section: MemorySection) -> String {
+    fs::read_to_string(section_path(project_path, section)).unwrap_or_default()
+}
+
+fn read_global_section(section: MemorySection) -> String {
+    global_memory_dir()
+        .ok()
+        .map(|dir| fs::read_to_string(dir.join(section.file_name())).unwrap_or_default())
+        .unwrap_or_default()
+}
+
+/// Concatenated global memory, with section headers. Empty when nothing has
+/// been written yet.
+pub fn read_global() -> String {
+    let mut combined = String::new();
+    for section in MemorySection::ALL {
+        let content = read_global_section(section);
+        if !content.is_empty() {
+            combined.push_str(&format!(
+                "## {}\n\n{}\n\n",
+                section.file_name().trim_end_matches(".md"),
+                content
+            ));
+        }
+    }
+    combined
+}
+
+/// Concatenated contents of every memory section, with headers.
+pub fn read_all(project_path: &Path) -> String {
+    let mut combined = String::new();
+    for section in MemorySection::ALL {
+        let content = read_section(project_path, section);
+        if !content.is_empty() {
+            combined.push_str(&format!(
+                "## {}\n\n{}\n\n",
+                section.file_name().trim_end_matches(".md"),
+                content
+            ));
+        }
+    }
+    combined
+}
+
+/// Write a section atomically (temp file + rename) under the write lock.
+pub fn write_section(
+    project_path: &Path,
+    section: MemorySection,
+    content: &str,
+    mode: WriteMode,
+) -> Result<(), String> {
+    let _guard = WRITE_LOCK.lock().unwrap();
+
+    let path = section_path(project_path, section);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let new_content = match mode {
+        WriteMode::Replace => content.to_string(),
+        WriteMode::Append => {
+            let mut existing = fs::read_to_string(&path).unwrap_or_default();
+            if !existing.is_empty() && !existing.ends_with('\n') {
+                existing.push('\n');
+            }
+            if !existing.is_empty() {
+                existing.push('\n');
+            }
+            existing.push_str(content);
+            existing
+        }
+    };
+
+    let tmp = path.with_extension("md.tmp");
+    fs::write(&tmp, &new_content).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, &path).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryEntry {
+    /// Position within the section, used to address edits and deletes.
+    pub index: usize,
+    pub heading: String,
+    pub body: String,
+    /// First YYYY-MM-DD found in the heading or body, if any.
+    pub date: Option<String>,
+    /// Parsed from a `Tags: a, b` line in the body.
+    pub tags: Vec<String>,
+}
+
+/// Parse a section into `### `-delimited entries. Content before the first
+/// heading is returned as a preamble so rewrites don't lose it.
+pub fn parse_entries(content: &str) -> (String, Vec<MemoryEntry>) {
+    let mut preamble = String::new();
+    let mut entries: Vec<MemoryEntry> = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in content.lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            if let Some((heading, body)) = current.take() {
+                entries.push(build_entry(entries.len(), heading, body));
+            }
+            current = Some((heading.trim().to_string(), String::new()));
+        } else {
+            match &mut current {
+                Some((_, body)) => {
+                    body.push_str(line);
+                    body.push('\n');
+                }
+                None => {
+                    preamble.push_str(line);
+                    preamble.push('\n');
+                }
+            }
+        }
+    }
+    if let Some((heading, body)) = current {
+        entries.push(build_entry(entries.len(), heading, body));
+    }
+    (preamble.trim_end().to_string(), entries)
+}
+
+fn build_entry(index: usize, heading: String, body: String) -> MemoryEntry {
+    let tags = body
+        .lines()
+        .find_map(|l| l.strip_prefix("Tags:"))
+        .map(|t| {
+            t.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    let date = find_date(&heading).or_else(|| find_date(&body));
+    MemoryEntry {
+        index,
+        heading,
+        body: body.trim_end().to_string(),
+        date,
+        tags,
+    }
+}
+
+/// First YYYY-MM-DD substring, if present. Scans bytes rather than
+/// indexing the `&str`, so multi-byte text can't land a slice on a
+/// non-char boundary.
+fn find_date(text: &str) -> Option<String> {
+    text.as_bytes()
+        .windows(10)
+        .find(|w| {
+            w.iter().enumerate().all(|(i, b)| {
+                if matches!(i, 4 | 7) {
+                    *b == b'-'
+                } else {
+                    b.is_ascii_digit()
+                }
+            })
+        })
+        // The window is all ASCII, so this conversion is lossless.
+        .map(|w| String::from_utf8_lossy(w).into_owned())
+}
+
+fn render_entries(preamble: &str, entries: &[MemoryEntry]) -> String {
+    let mut out = String::new();
+    if !preamble.is_empty() {
+        out.push_str(preamble);
+        out.push_str("\n\n");
+    }
+    for entry in entries {
+        out.push_str(&format!("### {}\n", entry.heading));
+        if !entry.body.is_empty() {
+            out.push_str(&entry.body);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out.trim_end().to_string()
+}
+
+/// A section parsed into individual entries for list rendering in the UI.
+#[tauri::command]
+pub fn list_memory_entries(
+    project_path: String,
+    section: MemorySection,
+) -> Result<Vec<MemoryEntry>, String> {
+    let content = read_section(Path::new(&project_path), section);
+    Ok(parse_entries(&content).1)
+}
+
+/// Append a new entry to a section.
+#[tauri::command]
+pub fn add_memory_entry(
+    app: AppHandle,
+    project_path: String,
+    section: MemorySection,
+    heading: String,
+    body: String,
+) -> Result<(), String> {
+    let entry = format!("### {}\n{}", heading, body.trim_end());
+    update_project_memory(app, project_path, section, entry, WriteMode::Append)
+}
+
+/// Replace an entry's heading and body by index.
+#[tauri::command]
+pub fn edit_memory_entry(
+    app: AppHandle,
+    project_path: String,
+    section: MemorySection,
+    index: usize,
+    heading: String,
+    body: String,
+) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let (preamble, mut entries) = parse_entries(&read_section(path, section));
+    let entry = entries
+        .get_mut(index)
+        .ok_or_else(|| format!("No entry at index {}", index))?;
+    entry.heading = heading;
+    entry.body = body.trim_end().to_string();
+    let rendered = render_entries(&preamble, &entries);
+    update_project_memory(app, project_path, section, rendered, WriteMode::Replace)
+}
+
+/// Delete an entry by index.
+#[tauri::command]
+pub fn delete_memory_entry(
+    app: AppHandle,
+    project_path: String,
+    section: MemorySection,
+    index: usize,
+) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let (preamble, mut entries) = parse_entries(&read_section(path, section));
+    if index >= entries.len() {
+        return Err(format!("No entry at index {}", index));
+    }
+    entries.remove(index);
+    let rendered = render_entries(&preamble, &entries);
+    update_project_memory(app, project_path, section, rendered, WriteMode::Replace)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryMatch {
+    pub project: String,
+    pub section: MemorySection,
+    pub line_number: usize,
+    pub line: String,
+}
+
+/// Case-insensitive search over one project's memory sections.
+pub fn search_project(project_path: &Path, project: &str, query: &str) -> Vec<MemoryMatch> {
+    let needle = query.to_lowercase();
+    let mut matches = Vec::new();
+    for section in MemorySection::ALL {
+        for (index, line) in read_section(project_path, section).lines().enumerate() {
+            if line.to_lowercase().contains(&needle) {
+                matches.push(MemoryMatch {
+                    project: project.to_string(),
+                    section,
+                    line_number: index + 1,
+                    line: line.to_string(),
+                });
+            }
+        }
+    }
+    matches
+}
+
+/// Search the memory of every tracked project, so lessons learned in one
+/// project are findable from any other.
+#[tauri::command]
+pub fn search_memory(query: String) -> Result<Vec<MemoryMatch>, String> {
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut matches = Vec::new();
+    for path in crate::commands::read_tracked_projects()? {
+        let project = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        matches.extend(search_project(&path, &project, &query));
+    }
+    Ok(matches)
+}
+
+/// Update a project's memory from the UI or a backend pipeline.
+#[tauri::command]
+pub fn update_project_memory(
+    app: AppHandle,
+    project_path: String,
+    section: MemorySection,
+    content: String,
+    mode: WriteMode,
+) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    write_section(path, section, &content, mode)?;
+    if let Err(e) = commit_memory_change(path, section) {
+        log::warn!("Failed to record memory history: {}", e);
+    }
+    let _ = app.emit(
+        "memory-updated",
+        serde_json::json!({
+            "projectPath": project_path,
+            "section": section,
+        }),
+    );
+    Ok(())
+}
+
+/// The repo that tracks memory history: the project repo when it is one,
+/// otherwise a dedicated repo initialized inside `.claude/memory/`.
+fn history_repo(project_path: &Path) -> Result<(PathBuf, PathBuf), String> {
+    let section_rel = PathBuf::from(".claude").join("memory");
+    if project_path.join(".git").exists() {
+        return Ok((project_path.to_path_buf(), section_rel));
+    }
+    let dir = memory_dir(project_path);
+    if !dir.join(".git").exists() {
+        crate::git::run_git(&dir, &["init"])?;
+    }
+    Ok((dir, PathBuf::new()))
+}
+
+/// Commit the section file so every memory edit is recoverable.
+fn commit_memory_change(project_path: &Path, section: MemorySection) -> Result<(), String> {
+    let (repo, rel) = history_repo(project_path)?;
+    let file = rel.join(section.file_name());
+    let file = file.to_string_lossy();
+    crate::git::run_git(&repo, &["add", &file])?;
+    // Nothing staged (content unchanged) is fine; don't surface the error.
+    let message = format!("memory: update {}", section.file_name());
+    let _ = crate::git::run_git(&repo, &["commit", "-m", &message, "--", &file]);
+    Ok(())
+}
+
+/// Global (cross-project) memory for the UI.
+#[tauri::command]
+pub fn get_global_memory() -> Result<String, String> {
+    Ok(read_global())
+}
+
+/// Update global memory. Same append/replace semantics as project memory.
+#[tauri::command]
+pub fn update_global_memory(
+    app: AppHandle,
+    section: MemorySection,
+    content: String,
+    mode: WriteMode,
+) -> Result<(), String> {
+    let _guard = WRITE_LOCK.lock().unwrap();
+    let dir = global_memory_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(section.file_name());
+
+    let new_content = match mode {
+        WriteMode::Replace => content,
+        WriteMode::Append => {
+            let mut existing = fs::read_to_string(&path).unwrap_or_default();
+            if !existing.is_empty() && !existing.ends_with('\n') {
+                existing.push('\n');
+            }
+            if !existing.is_empty() {
+                existing.push('\n');
+            }
+            existing.push_str(&content);
+            existing
+        }
+    };
+    let tmp = path.with_extension("md.tmp");
+    fs::write(&tmp, &new_content).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, &path).map_err(|e| e.to_string())?;
+
+    let _ = app.emit("memory-updated", serde_json::json!({ "section": section }));
+    Ok(())
+}
+
+/// The change history of a memory section, newest first.
+#[tauri::command]
+pub fn get_memory_history(
+    project_path: String,
+    section: MemorySection,
+) -> Result<Vec<crate::git::CommitInfo>, String> {
+    let (repo, rel) = history_repo(Path::new(&project_path))?;
+    let file = rel.join(section.file_name());
+    let file = file.to_string_lossy();
+    let output = crate::git::run_git(
+        &repo,
+        &[
+            "log",
+            "--pretty=format:%H%x1f%an%x1f%aI%x1f%s",
+            "--",
+            &file,
+        ],
+    )?;
+    Ok(crate::git::parse_log(&output))
+}
@@ -0,0 +1,214 @@
+//! Notification engine.
+//!
+//! Renders per-event-type voice messages from templates before dispatch, so
+//! phrasing lives in one configurable place instead of being hardcoded in
+//! the frontend. Templates support `{variable}` placeholders (e.g.
+//! `{project}`, `{issue}`) and can be overridden per event type in settings.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{Datelike, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{self, QuietWindow};
+use crate::speech::{self, SpeechPriority};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    AgentComplete,
+    AgentFailed,
+    BudgetAlert,
+    PrOpened,
+    PrMerged,
+}
+
+impl NotificationType {
+    /// Key used for template overrides in settings.
+    pub fn key(self) -> &'static str {
+        match self {
+            NotificationType::AgentComplete => "agent_complete",
+            NotificationType::AgentFailed => "agent_failed",
+            NotificationType::BudgetAlert => "budget_alert",
+            NotificationType::PrOpened => "pr_opened",
+            NotificationType::PrMerged => "pr_merged",
+        }
+    }
+
+    fn default_template(self) -> &'static str {
+        match self {
+            NotificationType::AgentComplete => "Agent for {project} completed successfully.",
+            NotificationType::AgentFailed => "Agent for {project} failed on issue {issue}.",
+            NotificationType::BudgetAlert => {
+                "Budget alert: you have spent {amount} dollars this month."
+            }
+            NotificationType::PrOpened => "A pull request is ready for review in {project}.",
+            NotificationType::PrMerged => "Pull request {number} merged in {project}.",
+        }
+    }
+
+    fn priority(self) -> SpeechPriority {
+        match self {
+            NotificationType::AgentFailed | NotificationType::BudgetAlert => {
+                SpeechPriority::Critical
+            }
+            _ => SpeechPriority::Normal,
+        }
+    }
+}
+
+/// Substitute `{name}` placeholders. Unknown placeholders are left as-is so
+/// a typo in a custom template is visible rather than silently dropped.
+pub fn render_template(template: &str, variables: &HashMap<String, String>) -> String {
+    let mut rendered = template.to_string();
+    for (name, value) in variables {
+        rendered = rendered.replace(&format!("{{{}}}", name), value);
+    }
+    rendered
+}
+
+/// The template for an event type: the settings override when present,
+/// otherwise the built-in default.
+pub fn template_for(loaded: &settings::Settings, event_type: NotificationType) -> String {
+    loaded
+        .notification_templates
+        .get(event_type.key())
+        .cloned()
+        .unwrap_or_else(|| event_type.default_template().to_string())
+}
+
+/// Notifications held back during quiet hours, oldest first.
+static DEFERRED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// "HH:MM" to minute-of-day.
+fn parse_hhmm(value: &str) -> Option<u32> {
+    let (hours, minutes) = value.split_once(':')?;
+    let hours: u32 = hours.parse().ok()?;
+    let minutes: u32 = minutes.parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(hours * 60 + minutes)
+}
+
+/// Whether a window covers the given weekday ("mon".."sun") and minute of
+/// day. Overnight windows (start > end) match from their start day's evening
+/// through the following morning.
+pub fn window_covers(window: &QuietWindow, weekday: &str, minute: u32) -> bool {
+    let (Some(start), Some(end)) = (parse_hhmm(&window.start), parse_hhmm(&window.end)) else {
+        return false;
+    };
+    let day_matches = |day: &str| {
+        window.days.is_empty() || window.days.iter().any(|d| d.eq_ignore_ascii_case(day))
+    };
+    if start <= end {
+        day_matches(weekday) && minute >= start && minute < end
+    } else {
+        // Evening side on the start day, morning side on the next.
+        let previous = match weekday {
+            "mon" => "sun",
+            "tue" => "mon",
+            "wed" => "tue",
+            "thu" => "wed",
+            "fri" => "thu",
+            "sat" => "fri",
+            _ => "sat",
+        };
+        (day_matches(weekday) && minute >= start) || (day_matches(previous) && minute < end)
+    }
+}
+
+fn current_weekday_and_minute() -> (String, u32) {
+    let now = chrono::Local::now();
+    let weekday = match now.weekday() {
+        chrono::Weekday::Mon => "mon",
+        chrono::Weekday::Tue => "tue",
+        chrono::Weekday::Wed => "wed",
+        chrono::Weekday::Thu => "thu",
+        chrono::Weekday::Fri => "fri",
+        chrono::Weekday::Sat => "sat",
+        chrono::Weekday::Sun => "sun",
+    };
+    (weekday.to_string(), now.hour() * 60 + now.minute())
+}
+
+fn in_quiet_hours(loaded: &settings::Settings) -> bool {
+    let (weekday, minute) = current_weekday_and_minute();
+    loaded
+        .quiet_hours
+        .iter()
+        .any(|w| window_covers(w, &weekday, minute))
+}
+
+/// Speak a digest of everything deferred during quiet hours.
+fn flush_deferred() {
+    let held: Vec<String> = {
+        let mut deferred = DEFERRED.lock().unwrap();
+        std::mem::take(&mut *deferred)
+    };
+    if held.is_empty() {
+        return;
+    }
+    let summary = if held.len() == 1 {
+        format!("While you were away: {}", held[0])
+    } else {
+        format!(
+            "While you were away, {} notifications. {}",
+            held.len(),
+            held.join(" ")
+        )
+    };
+    speech::enqueue(summary, SpeechPriority::Normal);
+}
+
+/// Periodically flush deferred notifications once quiet hours end, so the
+/// catch-up summary doesn't wait for the next live event.
+pub fn start_quiet_hours_flusher() {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            let Ok(loaded) = settings::load_settings() else {
+                continue;
+            };
+            if !in_quiet_hours(&loaded) {
+                flush_deferred();
+            }
+        }
+    });
+}
+
+/// Render and dispatch a notification. Backend subsystems call this instead
+/// of phrasing messages themselves. During quiet hours the message is queued
+/// and summarized once the window ends.
+pub fn dispatch(
+    event_type: NotificationType,
+    variables: &HashMap<String, String>,
+) -> Result<String, String> {
+    let loaded = settings::load_settings()?;
+    let message = render_template(&template_for(&loaded, event_type), variables);
+    // Per-project mutes silence the voice but still return the rendered
+    // message for toasts and logs.
+    let project_muted = variables
+        .get("project")
+        .is_some_and(|project| crate::commands::is_project_muted(project));
+    if loaded.voice_notifications_enabled && !project_muted {
+        if in_quiet_hours(&loaded) {
+            DEFERRED.lock().unwrap().push(message.clone());
+        } else {
+            flush_deferred();
+            speech::enqueue(message.clone(), event_type.priority());
+        }
+    }
+    Ok(message)
+}
+
+/// Frontend entry point: render an event notification and speak it.
+#[tauri::command]
+pub fn notify(
+    event_type: NotificationType,
+    variables: HashMap<String, String>,
+) -> Result<String, String> {
+    dispatch(event_type, &variables)
+}
@@ -0,0 +1,147 @@
+//! First-run onboarding checks.
+//!
+//! The setup wizard asks the backend which prerequisites are actually
+//! satisfied (keys, gh auth, git identity, a tracked project) instead of
+//! guessing from frontend state. Steps the user explicitly dismisses are
+//! persisted in `~/.claude/sentra/onboarding.json`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands;
+use crate::settings;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingStep {
+    /// Stable id accepted by [`complete_onboarding_step`].
+    pub id: String,
+    pub label: String,
+    /// Whether the backend check passed.
+    pub satisfied: bool,
+    /// Whether the user marked the step done (e.g. skipped an optional key).
+    pub acknowledged: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingStatus {
+    pub steps: Vec<OnboardingStep>,
+    /// True once every step is satisfied or acknowledged.
+    pub complete: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct OnboardingState {
+    #[serde(default)]
+    acknowledged_steps: Vec<String>,
+}
+
+fn state_path() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("onboarding.json"))
+}
+
+fn load_state() -> Result<OnboardingState, String> {
+    let path = state_path()?;
+    if !path.exists() {
+        return Ok(OnboardingState::default());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid onboarding state: {}", e))
+}
+
+fn write_state(state: &OnboardingState) -> Result<(), String> {
+    let path = state_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+/// Whether gh has usable GitHub credentials (a configured token counts).
+fn github_authenticated(loaded: &settings::Settings) -> bool {
+    if !loaded.github_token.is_empty() {
+        return true;
+    }
+    Command::new("gh")
+        .args(["auth", "status"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Whether git has a global user.name and user.email.
+fn git_identity_configured() -> bool {
+    ["user.name", "user.email"].iter().all(|key| {
+        Command::new("git")
+            .args(["config", "--global", "--get", key])
+            .output()
+            .map(|o| o.status.success() && !o.stdout.is_empty())
+            .unwrap_or(false)
+    })
+}
+
+fn step(id: &str, label: &str, satisfied: bool, state: &OnboardingState) -> OnboardingStep {
+    OnboardingStep {
+        id: id.to_string(),
+        label: label.to_string(),
+        satisfied,
+        acknowledged: state.acknowledged_steps.iter().any(|s| s == id),
+    }
+}
+
+/// Report which onboarding prerequisites are met.
+#[tauri::command]
+pub fn get_onboarding_status() -> Result<OnboardingStatus, String> {
+    let loaded = settings::load_settings()?;
+    let state = load_state()?;
+    let steps = vec![
+        step(
+            "anthropic_key",
+            "Anthropic API key configured",
+            !loaded.anthropic_api_key.is_empty(),
+            &state,
+        ),
+        step(
+            "openai_key",
+            "OpenAI API key configured (voice features)",
+            !loaded.openai_api_key.is_empty(),
+            &state,
+        ),
+        step(
+            "github_auth",
+            "GitHub authenticated (gh login or token)",
+            github_authenticated(&loaded),
+            &state,
+        ),
+        step(
+            "git_identity",
+            "Git identity configured",
+            git_identity_configured(),
+            &state,
+        ),
+        step(
+            "tracked_project",
+            "At least one project tracked",
+            !commands::read_tracked_projects()?.is_empty(),
+            &state,
+        ),
+    ];
+    let complete = steps.iter().all(|s| s.satisfied || s.acknowledged);
+    Ok(OnboardingStatus { steps, complete })
+}
+
+/// Persist that the user completed or dismissed a wizard step.
+#[tauri::command]
+pub fn complete_onboarding_step(step: String) -> Result<OnboardingStatus, String> {
+    let mut state = load_state()?;
+    if !state.acknowledged_steps.contains(&step) {
+        state.acknowledged_steps.push(step);
+        write_state(&state)?;
+    }
+    get_onboarding_status()
+}
@@ -0,0 +1,30 @@
+//! Lightweight runtime metrics for the debug panel.
+
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+static STARTED: OnceLock<Instant> = OnceLock::new();
+
+/// Record process start. Idempotent; the first call wins.
+pub fn mark_started() {
+    let _ = STARTED.get_or_init(Instant::now);
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceMetrics {
+    pub uptime_seconds: u64,
+    pub tracked_projects: usize,
+}
+
+#[tauri::command]
+pub fn get_performance_metrics() -> Result<PerformanceMetrics, String> {
+    let uptime_seconds = STARTED.get().map(|s| s.elapsed().as_secs()).unwrap_or(0);
+    let tracked_projects = crate::commands::read_tracked_projects()?.len();
+    Ok(PerformanceMetrics {
+        uptime_seconds,
+        tracked_projects,
+    })
+}
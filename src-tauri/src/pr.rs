@@ -0,0 +1,1127 @@
+//! Pull request operations.
+//!
+//! Every command talks to the GitHub REST API directly when a token is
+//! configured in settings, and falls back to the gh CLI otherwise, so
+//! users without gh installed still get the full PR workflow.
+
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+/// Run gh with the given args and parse stdout as JSON.
+pub fn run_gh_json<T: serde::de::DeserializeOwned>(args: &[&str]) -> Result<T, String> {
+    let stdout = run_gh(args)?;
+    serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse gh output: {}", e))
+}
+
+pub fn run_gh(args: &[&str]) -> Result<String, String> {
+    crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+    let output = Command::new("gh")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run gh: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Whether to use the native REST client instead of the gh CLI.
+fn native() -> bool {
+    crate::github::token().is_some()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequest {
+    pub number: u64,
+    pub title: String,
+    pub author: String,
+    pub branch: String,
+    pub state: String,
+    pub checks_status: String,
+    /// Individual check runs behind the rollup, so the PR view can show
+    /// which check failed rather than an opaque "failing".
+    #[serde(default)]
+    pub checks: Vec<CheckRun>,
+    pub url: String,
+    pub created_at: String,
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+/// One CI check run on a PR's head commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckRun {
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub duration_seconds: i64,
+    /// Link to the check's log or details page.
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhPr {
+    number: u64,
+    title: String,
+    author: GhAuthor,
+    #[serde(rename = "headRefName")]
+    head_ref_name: String,
+    state: String,
+    url: String,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    #[serde(default)]
+    additions: u64,
+    #[serde(default)]
+    deletions: u64,
+    #[serde(rename = "statusCheckRollup", default)]
+    status_check_rollup: Vec<GhCheck>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhAuthor {
+    login: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GhCheck {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    conclusion: Option<String>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(rename = "startedAt", default)]
+    started_at: Option<String>,
+    #[serde(rename = "completedAt", default)]
+    completed_at: Option<String>,
+    #[serde(rename = "detailsUrl", default)]
+    details_url: Option<String>,
+}
+
+impl GhCheck {
+    fn into_check_run(self) -> CheckRun {
+        CheckRun {
+            name: self.name.unwrap_or_default(),
+            status: self.status.clone().unwrap_or_default(),
+            conclusion: self.conclusion,
+            duration_seconds: crate::agents::duration_seconds(
+                self.started_at.as_deref(),
+                self.completed_at.as_deref(),
+            ),
+            url: self.details_url.unwrap_or_default(),
+        }
+    }
+}
+
+/// The REST representation of a pull request.
+#[derive(Debug, Deserialize)]
+struct RestPr {
+    number: u64,
+    title: String,
+    user: RestUser,
+    head: RestHead,
+    state: String,
+    html_url: String,
+    created_at: String,
+    #[serde(default)]
+    additions: u64,
+    #[serde(default)]
+    deletions: u64,
+    #[serde(default)]
+    merged_at: Option<String>,
+    #[serde(default)]
+    labels: Vec<RestLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestHead {
+    #[serde(rename = "ref")]
+    branch: String,
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestLabel {
+    name: String,
+}
+
+/// Whether a check conclusion counts as a failure. gh reports upper-case
+/// values and REST lower-case ones, so matching ignores case.
+fn check_failed(conclusion: &str) -> bool {
+    ["failure", "timed_out", "cancelled"]
+        .iter()
+        .any(|bad| conclusion.eq_ignore_ascii_case(bad))
+}
+
+/// Collapse a PR's check runs into a single "passing"/"failing"/"pending"
+/// string for the list view.
+fn summarize_checks(checks: &[CheckRun]) -> String {
+    if checks.is_empty() {
+        return "none".to_string();
+    }
+    let mut pending = false;
+    for check in checks {
+        match check.conclusion.as_deref() {
+            Some(conclusion) if check_failed(conclusion) => return "failing".to_string(),
+            Some(_) => {}
+            None => {
+                if !check.status.eq_ignore_ascii_case("completed") {
+                    pending = true;
+                }
+            }
+        }
+    }
+    if pending {
+        "pending".to_string()
+    } else {
+        "passing".to_string()
+    }
+}
+
+fn convert_pr(pr: GhPr) -> PullRequest {
+    let checks: Vec<CheckRun> = pr
+        .status_check_rollup
+        .into_iter()
+        .map(GhCheck::into_check_run)
+        .collect();
+    PullRequest {
+        number: pr.number,
+        title: pr.title,
+        author: pr.author.login,
+        branch: pr.head_ref_name,
+        state: pr.state,
+        checks_status: summarize_checks(&checks),
+        checks,
+        url: pr.url,
+        created_at: pr.created_at,
+        additions: pr.additions,
+        deletions: pr.deletions,
+    }
+}
+
+/// Check runs for a commit via the REST check-runs endpoint.
+async fn fetch_check_runs(owner: &str, repo: &str, sha: &str) -> Vec<CheckRun> {
+    let path = format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, sha);
+    let Ok(response) = crate::github::get_json(&path).await else {
+        return Vec::new();
+    };
+    let str_field = |run: &serde_json::Value, key: &str| {
+        run.get(key).and_then(|v| v.as_str()).map(String::from)
+    };
+    response
+        .get("check_runs")
+        .and_then(|runs| runs.as_array())
+        .map(|runs| {
+            runs.iter()
+                .map(|run| CheckRun {
+                    name: str_field(run, "name").unwrap_or_default(),
+                    status: str_field(run, "status").unwrap_or_default(),
+                    conclusion: str_field(run, "conclusion"),
+                    duration_seconds: crate::agents::duration_seconds(
+                        run.get("started_at").and_then(|v| v.as_str()),
+                        run.get("completed_at").and_then(|v| v.as_str()),
+                    ),
+                    url: str_field(run, "html_url").unwrap_or_default(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn convert_rest_pr(owner: &str, repo: &str, pr: RestPr) -> PullRequest {
+    let checks = fetch_check_runs(owner, repo, &pr.head.sha).await;
+    PullRequest {
+        number: pr.number,
+        title: pr.title,
+        author: pr.user.login,
+        branch: pr.head.branch,
+        // gh reports states upper-case; keep the shape consistent.
+        state: pr.state.to_uppercase(),
+        checks_status: summarize_checks(&checks),
+        checks,
+        url: pr.html_url,
+        created_at: pr.created_at,
+        additions: pr.additions,
+        deletions: pr.deletions,
+    }
+}
+
+const PR_FIELDS: &str = "number,title,author,headRefName,state,url,createdAt,statusCheckRollup";
+
+/// Open pull requests for a repository.
+#[tauri::command]
+pub async fn get_pull_requests(owner: String, repo: String) -> Result<Vec<PullRequest>, String> {
+    if let Some(provider) = crate::git_provider::non_github() {
+        return provider
+            .list_pull_requests(&format!("{}/{}", owner, repo))
+            .await;
+    }
+    if native() {
+        let path = format!("/repos/{}/{}/pulls?state=open&per_page=50", owner, repo);
+        let prs: Vec<RestPr> = serde_json::from_value(crate::github::get_json(&path).await?)
+            .map_err(|e| format!("Failed to parse GitHub response: {}", e))?;
+        let mut out = Vec::with_capacity(prs.len());
+        for pr in prs {
+            out.push(convert_rest_pr(&owner, &repo, pr).await);
+        }
+        return Ok(out);
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let prs: Vec<GhPr> = run_gh_json(&[
+        "pr", "list", "--repo", &repo_arg, "--state", "open", "--json", PR_FIELDS,
+    ])?;
+    Ok(prs.into_iter().map(convert_pr).collect())
+}
+
+/// A single pull request with check summary and diff stats.
+#[tauri::command]
+pub async fn get_pull_request(
+    owner: String,
+    repo: String,
+    number: u64,
+) -> Result<PullRequest, String> {
+    if let Some(provider) = crate::git_provider::non_github() {
+        return provider
+            .get_pull_request(&format!("{}/{}", owner, repo), number)
+            .await;
+    }
+    if native() {
+        let path = format!("/repos/{}/{}/pulls/{}", owner, repo, number);
+        let pr: RestPr = serde_json::from_value(crate::github::get_json(&path).await?)
+            .map_err(|e| format!("Failed to parse GitHub response: {}", e))?;
+        return Ok(convert_rest_pr(&owner, &repo, pr).await);
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    let fields = format!("{},additions,deletions", PR_FIELDS);
+    let pr: GhPr = run_gh_json(&[
+        "pr", "view", &number_arg, "--repo", &repo_arg, "--json", &fields,
+    ])?;
+    Ok(convert_pr(pr))
+}
+
+/// The full unified diff of a pull request.
+#[tauri::command]
+pub async fn get_pr_diff(owner: String, repo: String, number: u64) -> Result<String, String> {
+    if let Some(provider) = crate::git_provider::non_github() {
+        return provider
+            .get_pr_diff(&format!("{}/{}", owner, repo), number)
+            .await;
+    }
+    if native() {
+        let path = format!("/repos/{}/{}/pulls/{}", owner, repo, number);
+        return crate::github::get_raw(&path, "application/vnd.github.diff").await;
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    run_gh(&["pr", "diff", &number_arg, "--repo", &repo_arg])
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewFinding {
+    pub file: String,
+    /// Line in the new version of the file; absent for file-level remarks.
+    pub line: Option<u32>,
+    /// One of "critical", "warning", "nit".
+    pub severity: String,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiReview {
+    pub summary: String,
+    pub findings: Vec<ReviewFinding>,
+    /// Whether the findings were posted as a pending GitHub review.
+    pub posted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewResponse {
+    summary: String,
+    #[serde(default)]
+    findings: Vec<ReviewFinding>,
+}
+
+const REVIEW_SYSTEM: &str = "You are a senior engineer reviewing a pull request. \
+Given the unified diff (and project memory with known gotchas and conventions), \
+return ONLY a JSON object: {\"summary\": \"one-paragraph assessment\", \
+\"findings\": [{\"file\": \"path\", \"line\": 123, \"severity\": \
+\"critical\"|\"warning\"|\"nit\", \"comment\": \"...\"}]}. `line` refers to the \
+new file version and may be null for file-level remarks. Flag real problems \
+(bugs, security, convention violations); do not pad with filler findings.";
+
+/// Diffs beyond this are truncated before review; the head of a diff carries
+/// the most-changed files.
+const MAX_REVIEW_DIFF_BYTES: usize = 60_000;
+
+/// Parse the model's review, tolerating markdown fences around the JSON.
+fn parse_review(response: &str) -> Result<ReviewResponse, String> {
+    let trimmed = response.trim();
+    let json = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .map(|s| s.trim_end_matches("```").trim())
+        .unwrap_or(trimmed);
+    serde_json::from_str(json).map_err(|e| format!("Unparseable review response: {}", e))
+}
+
+/// Post findings as a pending review so the user can edit and submit it.
+async fn post_pending_review(
+    owner: &str,
+    repo: &str,
+    number: u64,
+    summary: &str,
+    findings: &[ReviewFinding],
+) -> Result<(), String> {
+    let comments: Vec<serde_json::Value> = findings
+        .iter()
+        .filter_map(|f| {
+            f.line.map(|line| {
+                serde_json::json!({
+                    "path": f.file,
+                    "line": line,
+                    "side": "RIGHT",
+                    "body": format!("[{}] {}", f.severity, f.comment),
+                })
+            })
+        })
+        .collect();
+    // No "event" field: GitHub creates the review as PENDING.
+    let body = serde_json::json!({ "body": summary, "comments": comments });
+    let endpoint = format!("repos/{}/{}/pulls/{}/reviews", owner, repo, number);
+
+    if native() {
+        return crate::github::post_json(&format!("/{}", endpoint), &body)
+            .await
+            .map(|_| ());
+    }
+
+    let input = std::env::temp_dir().join(format!("sentra-review-{}.json", std::process::id()));
+    std::fs::write(&input, body.to_string()).map_err(|e| e.to_string())?;
+    let result = run_gh(&[
+        "api",
+        "--method",
+        "POST",
+        &endpoint,
+        "--input",
+        input.to_str().unwrap_or_default(),
+    ]);
+    let _ = std::fs::remove_file(&input);
+    result.map(|_| ())
+}
+
+/// Review a pull request with the architect model: the diff plus project
+/// memory go in, structured findings come out. With `post` set, the findings
+/// are also left on GitHub as a pending review for the user to submit.
+#[tauri::command]
+pub async fn ai_review_pull_request(
+    owner: String,
+    repo: String,
+    number: u64,
+    project_path: Option<String>,
+    post: Option<bool>,
+) -> Result<AiReview, String> {
+    let mut diff = get_pr_diff(owner.clone(), repo.clone(), number).await?;
+    if diff.trim().is_empty() {
+        return Err("Pull request has an empty diff".to_string());
+    }
+    if diff.len() > MAX_REVIEW_DIFF_BYTES {
+        let mut cut = MAX_REVIEW_DIFF_BYTES;
+        while cut > 0 && !diff.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        diff.truncate(cut);
+        diff.push_str("\n[... diff truncated for review]");
+    }
+
+    let mut user = String::new();
+    if let Some(path) = &project_path {
+        let memory = crate::memory::read_all(std::path::Path::new(path));
+        if !memory.is_empty() {
+            user.push_str("# Project Memory\n\n");
+            user.push_str(&memory);
+            user.push_str("\n\n");
+        }
+    }
+    user.push_str("# Diff\n\n");
+    user.push_str(&diff);
+
+    let response = crate::architect::complete(REVIEW_SYSTEM, &user).await?;
+    let review = parse_review(&response)?;
+
+    let mut posted = false;
+    if post.unwrap_or(false) {
+        post_pending_review(&owner, &repo, number, &review.summary, &review.findings).await?;
+        posted = true;
+    }
+
+    Ok(AiReview {
+        summary: review.summary,
+        findings: review.findings,
+        posted,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeOutcome {
+    /// Whether the PR was merged by this call.
+    pub merged: bool,
+    /// Whether GitHub auto-merge was armed instead of merging now.
+    pub auto_merge: bool,
+    /// GitHub's mergeability verdict ("clean", "dirty", ...) when known.
+    pub mergeable_state: Option<String>,
+    pub message: String,
+}
+
+/// Mergeability and head-ref details for a PR, ahead of a merge attempt.
+struct MergeInfo {
+    mergeable: Option<bool>,
+    state: Option<String>,
+    branch: String,
+    node_id: Option<String>,
+}
+
+async fn merge_info(repo_arg: &str, number: u64) -> Result<MergeInfo, String> {
+    if native() {
+        let pr = crate::github::get_json(&format!("/repos/{}/pulls/{}", repo_arg, number)).await?;
+        return Ok(MergeInfo {
+            mergeable: pr.get("mergeable").and_then(|m| m.as_bool()),
+            state: pr
+                .get("mergeable_state")
+                .and_then(|s| s.as_str())
+                .map(String::from),
+            branch: pr
+                .pointer("/head/ref")
+                .and_then(|r| r.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            node_id: pr.get("node_id").and_then(|n| n.as_str()).map(String::from),
+        });
+    }
+
+    let number_arg = number.to_string();
+    let pr: serde_json::Value = run_gh_json(&[
+        "pr",
+        "view",
+        &number_arg,
+        "--repo",
+        repo_arg,
+        "--json",
+        "mergeable,mergeStateStatus,headRefName",
+    ])?;
+    let mergeable = match pr.get("mergeable").and_then(|m| m.as_str()) {
+        Some("MERGEABLE") => Some(true),
+        Some("CONFLICTING") => Some(false),
+        _ => None,
+    };
+    Ok(MergeInfo {
+        mergeable,
+        state: pr
+            .get("mergeStateStatus")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_lowercase()),
+        branch: pr
+            .get("headRefName")
+            .and_then(|b| b.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        node_id: None,
+    })
+}
+
+/// Squash-merge a pull request. `dry_run` only reports mergeability,
+/// `auto_merge` arms GitHub auto-merge instead of merging immediately,
+/// and `delete_branch` removes the head branch after the merge.
+#[tauri::command]
+pub async fn merge_pull_request(
+    owner: String,
+    repo: String,
+    number: u64,
+    delete_branch: Option<bool>,
+    auto_merge: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<MergeOutcome, String> {
+    let repo_arg = format!("{}/{}", owner, repo);
+    let delete_branch = delete_branch.unwrap_or(false);
+    let auto_merge = auto_merge.unwrap_or(false);
+
+    if let Some(provider) = crate::git_provider::non_github() {
+        if auto_merge || delete_branch || dry_run.unwrap_or(false) {
+            return Err(format!(
+                "Merge options are not supported on {} yet",
+                provider.name()
+            ));
+        }
+        provider.merge_pull_request(&repo_arg, number).await?;
+        return Ok(MergeOutcome {
+            merged: true,
+            auto_merge: false,
+            mergeable_state: None,
+            message: format!("PR #{} merged", number),
+        });
+    }
+
+    // Check mergeability up front: dry runs only report it, and real merge
+    // attempts fail fast with a clear message instead of an API error.
+    let info = merge_info(&repo_arg, number).await?;
+    if dry_run.unwrap_or(false) {
+        let message = match info.mergeable {
+            Some(true) => format!("PR #{} is mergeable", number),
+            Some(false) => format!("PR #{} has conflicts and cannot be merged", number),
+            None => format!("GitHub has not computed mergeability for PR #{} yet", number),
+        };
+        return Ok(MergeOutcome {
+            merged: false,
+            auto_merge: false,
+            mergeable_state: info.state,
+            message,
+        });
+    }
+    if info.mergeable == Some(false) {
+        return Err(format!(
+            "PR #{} has conflicts; resolve them before merging",
+            number
+        ));
+    }
+
+    if native() {
+        if auto_merge {
+            let node_id = info
+                .node_id
+                .ok_or_else(|| "Pull request has no node id".to_string())?;
+            crate::github::graphql(
+                "mutation($id: ID!) { enablePullRequestAutoMerge(input: { pullRequestId: $id, mergeMethod: SQUASH }) { clientMutationId } }",
+                serde_json::json!({ "id": node_id }),
+            )
+            .await?;
+            return Ok(MergeOutcome {
+                merged: false,
+                auto_merge: true,
+                mergeable_state: info.state,
+                message: format!("Auto-merge enabled for PR #{}", number),
+            });
+        }
+
+        let path = format!("/repos/{}/pulls/{}/merge", repo_arg, number);
+        crate::github::put(&path, &serde_json::json!({ "merge_method": "squash" })).await?;
+        if delete_branch && !info.branch.is_empty() {
+            // Branch deletion is best-effort; protected branches and forks
+            // reject it without invalidating the merge.
+            let _ = crate::github::delete(&format!(
+                "/repos/{}/git/refs/heads/{}",
+                repo_arg, info.branch
+            ))
+            .await;
+        }
+        return Ok(MergeOutcome {
+            merged: true,
+            auto_merge: false,
+            mergeable_state: info.state,
+            message: format!("PR #{} merged", number),
+        });
+    }
+
+    let number_arg = number.to_string();
+    let mut args = vec!["pr", "merge", &number_arg, "--repo", &repo_arg, "--squash"];
+    if auto_merge {
+        args.push("--auto");
+    }
+    if delete_branch {
+        args.push("--delete-branch");
+    }
+    run_gh(&args)?;
+    Ok(MergeOutcome {
+        merged: !auto_merge,
+        auto_merge,
+        mergeable_state: info.state,
+        message: if auto_merge {
+            format!("Auto-merge enabled for PR #{}", number)
+        } else {
+            format!("PR #{} merged", number)
+        },
+    })
+}
+
+/// Re-run the failed jobs of every failing workflow run on a PR's head
+/// commit. Returns how many runs were restarted.
+#[tauri::command]
+pub async fn rerun_failed_checks(owner: String, repo: String, number: u64) -> Result<u32, String> {
+    let repo_arg = format!("{}/{}", owner, repo);
+
+    let sha = if native() {
+        crate::github::get_json(&format!("/repos/{}/pulls/{}", repo_arg, number))
+            .await?
+            .pointer("/head/sha")
+            .and_then(|s| s.as_str())
+            .map(String::from)
+            .ok_or_else(|| "Pull request has no head commit".to_string())?
+    } else {
+        let number_arg = number.to_string();
+        run_gh_json::<serde_json::Value>(&[
+            "pr", "view", &number_arg, "--repo", &repo_arg, "--json", "headRefOid",
+        ])?
+        .get("headRefOid")
+        .and_then(|s| s.as_str())
+        .map(String::from)
+        .ok_or_else(|| "Pull request has no head commit".to_string())?
+    };
+
+    let runs_endpoint = format!("repos/{}/actions/runs?head_sha={}&per_page=50", repo_arg, sha);
+    let runs = if native() {
+        crate::github::get_json(&format!("/{}", runs_endpoint)).await?
+    } else {
+        run_gh_json::<serde_json::Value>(&["api", &runs_endpoint])?
+    };
+
+    let mut restarted = 0;
+    for run in runs
+        .get("workflow_runs")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let failed = run
+            .get("conclusion")
+            .and_then(|c| c.as_str())
+            .is_some_and(check_failed);
+        let Some(id) = run.get("id").and_then(|i| i.as_u64()).filter(|_| failed) else {
+            continue;
+        };
+        let result = if native() {
+            crate::github::post(
+                &format!("/repos/{}/actions/runs/{}/rerun-failed-jobs", repo_arg, id),
+                &serde_json::json!({}),
+            )
+            .await
+        } else {
+            run_gh(&[
+                "run",
+                "rerun",
+                &id.to_string(),
+                "--repo",
+                &repo_arg,
+                "--failed",
+            ])
+            .map(|_| ())
+        };
+        if result.is_ok() {
+            restarted += 1;
+        }
+    }
+
+    if restarted == 0 {
+        return Err("No failed check runs to re-run".to_string());
+    }
+    Ok(restarted)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewerCandidate {
+    pub login: String,
+    pub score: u32,
+    /// Why the candidate ranked: code ownership and/or recent commits.
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhPrFiles {
+    files: Vec<GhPrFile>,
+    author: GhAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhPrFile {
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhCommitListEntry {
+    author: Option<GhCommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhCommitAuthor {
+    login: String,
+}
+
+/// Whether a CODEOWNERS pattern matches a changed path. Supports the forms
+/// that show up in practice: `*`, `*.ext`, directory prefixes, and bare
+/// path prefixes.
+pub fn codeowners_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches('/');
+    if pattern == "*" {
+        return true;
+    }
+    if let Some(ext) = pattern.strip_prefix("*.") {
+        return path.rsplit('.').next() == Some(ext);
+    }
+    if let Some(dir) = pattern.strip_suffix('/') {
+        return path.starts_with(dir);
+    }
+    path == pattern || path.starts_with(&format!("{}/", pattern))
+}
+
+/// Owners for a path per CODEOWNERS semantics: the last matching rule wins.
+pub fn owners_for_path(codeowners: &str, path: &str) -> Vec<String> {
+    let mut owners = Vec::new();
+    for line in codeowners.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let Some(pattern) = parts.next() else { continue };
+        if codeowners_matches(pattern, path) {
+            owners = parts
+                .filter_map(|p| p.strip_prefix('@'))
+                .map(String::from)
+                .collect();
+        }
+    }
+    owners
+}
+
+/// Fetch the repo's CODEOWNERS file from any of its conventional locations.
+async fn fetch_codeowners(repo_arg: &str) -> Option<String> {
+    for location in [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"] {
+        let endpoint = format!("repos/{}/contents/{}", repo_arg, location);
+        let content = if native() {
+            crate::github::get_raw(&format!("/{}", endpoint), "application/vnd.github.raw").await
+        } else {
+            run_gh(&["api", &endpoint, "-H", "Accept: application/vnd.github.raw"])
+        };
+        if let Ok(content) = content {
+            return Some(content);
+        }
+    }
+    None
+}
+
+/// The changed paths and author of a PR, for reviewer ranking.
+async fn pr_files_and_author(
+    repo_arg: &str,
+    number: u64,
+) -> Result<(Vec<String>, String), String> {
+    if native() {
+        let pr = crate::github::get_json(&format!("/repos/{}/pulls/{}", repo_arg, number)).await?;
+        let author = pr
+            .pointer("/user/login")
+            .and_then(|l| l.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let files =
+            crate::github::get_json(&format!("/repos/{}/pulls/{}/files?per_page=100", repo_arg, number))
+                .await?;
+        let paths = files
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|f| f.get("filename").and_then(|n| n.as_str()))
+            .map(String::from)
+            .collect();
+        return Ok((paths, author));
+    }
+
+    let number_arg = number.to_string();
+    let pr: GhPrFiles = run_gh_json(&[
+        "pr", "view", &number_arg, "--repo", repo_arg, "--json", "files,author",
+    ])?;
+    Ok((
+        pr.files.into_iter().map(|f| f.path).collect(),
+        pr.author.login,
+    ))
+}
+
+/// Recent commit authors for a path, for reviewer ranking.
+async fn recent_committers(repo_arg: &str, path: &str) -> Vec<String> {
+    let endpoint = format!("repos/{}/commits?path={}&per_page=5", repo_arg, path);
+    let commits = if native() {
+        crate::github::get_json(&format!("/{}", endpoint))
+            .await
+            .ok()
+            .and_then(|v| serde_json::from_value::<Vec<GhCommitListEntry>>(v).ok())
+    } else {
+        run_gh_json::<Vec<GhCommitListEntry>>(&["api", &endpoint]).ok()
+    };
+    commits
+        .into_iter()
+        .flatten()
+        .filter_map(|c| c.author.map(|a| a.login))
+        .collect()
+}
+
+/// Ranked reviewer candidates for a PR, from CODEOWNERS rules plus recent
+/// commit authors of the changed files. The PR author is excluded; the
+/// result feeds straight into [`request_reviewers`].
+#[tauri::command]
+pub async fn suggest_reviewers(
+    owner: String,
+    repo: String,
+    number: u64,
+) -> Result<Vec<ReviewerCandidate>, String> {
+    let repo_arg = format!("{}/{}", owner, repo);
+    let (files, author) = pr_files_and_author(&repo_arg, number).await?;
+
+    let mut candidates: Vec<ReviewerCandidate> = Vec::new();
+    let mut bump = |login: &str, points: u32, reason: String| {
+        match candidates.iter_mut().find(|c| c.login == login) {
+            Some(c) => {
+                c.score += points;
+                if !c.reasons.contains(&reason) {
+                    c.reasons.push(reason);
+                }
+            }
+            None => candidates.push(ReviewerCandidate {
+                login: login.to_string(),
+                score: points,
+                reasons: vec![reason],
+            }),
+        }
+    };
+
+    if let Some(codeowners) = fetch_codeowners(&repo_arg).await {
+        for file in &files {
+            for reviewer in owners_for_path(&codeowners, file) {
+                // Team handles (org/team) can't be requested as users here.
+                if reviewer.contains('/') {
+                    continue;
+                }
+                bump(&reviewer, 10, format!("owns {}", file));
+            }
+        }
+    }
+
+    // Recent committers to the changed files know the code best. Cap the
+    // file fan-out so large PRs don't burn the API budget.
+    for file in files.iter().take(5) {
+        for login in recent_committers(&repo_arg, file).await {
+            bump(&login, 1, format!("recently touched {}", file));
+        }
+    }
+
+    candidates.retain(|c| c.login != author);
+    candidates.sort_by(|a, b| b.score.cmp(&a.score));
+    Ok(candidates)
+}
+
+#[derive(Debug, Deserialize)]
+struct GhMergedPr {
+    number: u64,
+    title: String,
+    author: GhAuthor,
+    url: String,
+    #[serde(rename = "mergedAt")]
+    merged_at: String,
+    #[serde(default)]
+    labels: Vec<GhLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhRelease {
+    #[serde(rename = "publishedAt")]
+    published_at: String,
+}
+
+/// Which changelog section a PR belongs in, from its labels with a
+/// conventional-commit-prefix fallback on the title.
+fn changelog_section(title: &str, labels: &[String]) -> &'static str {
+    let has = |needle: &str| labels.iter().any(|l| l.eq_ignore_ascii_case(needle));
+    if has("breaking") || has("breaking-change") {
+        return "Breaking Changes";
+    }
+    if has("feature") || has("enhancement") || has("ai-feature") || title.starts_with("feat") {
+        return "Features";
+    }
+    if has("bug") || has("fix") || title.starts_with("fix") {
+        return "Bug Fixes";
+    }
+    if has("documentation") || has("docs") || title.starts_with("docs") {
+        return "Documentation";
+    }
+    "Other Changes"
+}
+
+const CHANGELOG_SECTIONS: [&str; 5] = [
+    "Breaking Changes",
+    "Features",
+    "Bug Fixes",
+    "Documentation",
+    "Other Changes",
+];
+
+/// When a release tag was published, for changelog range filtering.
+async fn release_published_at(repo_arg: &str, tag: &str) -> Result<String, String> {
+    if native() {
+        let release =
+            crate::github::get_json(&format!("/repos/{}/releases/tags/{}", repo_arg, tag)).await?;
+        return release
+            .get("published_at")
+            .and_then(|p| p.as_str())
+            .map(String::from)
+            .ok_or_else(|| format!("Release {} has no publish date", tag));
+    }
+    let release: GhRelease = run_gh_json(&[
+        "release", "view", tag, "--repo", repo_arg, "--json", "publishedAt",
+    ])?;
+    Ok(release.published_at)
+}
+
+/// Recently merged PRs, newest first.
+async fn merged_pull_requests(repo_arg: &str) -> Result<Vec<GhMergedPr>, String> {
+    if native() {
+        let path = format!(
+            "/repos/{}/pulls?state=closed&sort=updated&direction=desc&per_page=100",
+            repo_arg
+        );
+        let prs: Vec<RestPr> = serde_json::from_value(crate::github::get_json(&path).await?)
+            .map_err(|e| format!("Failed to parse GitHub response: {}", e))?;
+        return Ok(prs
+            .into_iter()
+            .filter_map(|pr| {
+                pr.merged_at.map(|merged_at| GhMergedPr {
+                    number: pr.number,
+                    title: pr.title,
+                    author: GhAuthor {
+                        login: pr.user.login,
+                    },
+                    url: pr.html_url,
+                    merged_at,
+                    labels: pr
+                        .labels
+                        .into_iter()
+                        .map(|l| GhLabel { name: l.name })
+                        .collect(),
+                })
+            })
+            .collect());
+    }
+
+    run_gh_json(&[
+        "pr",
+        "list",
+        "--repo",
+        repo_arg,
+        "--state",
+        "merged",
+        "--limit",
+        "200",
+        "--json",
+        "number,title,author,url,mergedAt,labels",
+    ])
+}
+
+/// Grouped markdown release notes from PRs merged since `since_tag` (or all
+/// recent merges when no tag is given).
+#[tauri::command]
+pub async fn generate_changelog(
+    owner: String,
+    repo: String,
+    since_tag: Option<String>,
+) -> Result<String, String> {
+    let repo_arg = format!("{}/{}", owner, repo);
+
+    let since = match &since_tag {
+        Some(tag) => Some(release_published_at(&repo_arg, tag).await?),
+        None => None,
+    };
+
+    let prs = merged_pull_requests(&repo_arg).await?;
+
+    let mut sections: Vec<(&str, Vec<String>)> = CHANGELOG_SECTIONS
+        .iter()
+        .map(|&s| (s, Vec::new()))
+        .collect();
+    for pr in prs {
+        if let Some(since) = &since {
+            // ISO 8601 timestamps compare correctly as strings.
+            if pr.merged_at.as_str() <= since.as_str() {
+                continue;
+            }
+        }
+        let labels: Vec<String> = pr.labels.into_iter().map(|l| l.name).collect();
+        let section = changelog_section(&pr.title, &labels);
+        let line = format!(
+            "- {} ([#{}]({})) @{}",
+            pr.title, pr.number, pr.url, pr.author.login
+        );
+        if let Some((_, lines)) = sections.iter_mut().find(|(name, _)| *name == section) {
+            lines.push(line);
+        }
+    }
+
+    let mut out = match &since_tag {
+        Some(tag) => format!("## Changes since {}\n\n", tag),
+        None => "## Changes\n\n".to_string(),
+    };
+    let mut any = false;
+    for (name, lines) in &sections {
+        if lines.is_empty() {
+            continue;
+        }
+        any = true;
+        out.push_str(&format!("### {}\n\n", name));
+        for line in lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    if !any {
+        out.push_str("No merged pull requests in this range.\n");
+    }
+    Ok(out)
+}
+
+/// Request reviews from the given GitHub usernames.
+#[tauri::command]
+pub async fn request_reviewers(
+    owner: String,
+    repo: String,
+    number: u64,
+    reviewers: Vec<String>,
+) -> Result<(), String> {
+    if native() {
+        let path = format!(
+            "/repos/{}/{}/pulls/{}/requested_reviewers",
+            owner, repo, number
+        );
+        return crate::github::post_json(&path, &serde_json::json!({ "reviewers": reviewers }))
+            .await
+            .map(|_| ());
+    }
+
+    let repo_arg = format!("{}/{}", owner, repo);
+    let number_arg = number.to_string();
+    let reviewer_arg = reviewers.join(",");
+    run_gh(&[
+        "pr",
+        "edit",
+        &number_arg,
+        "--repo",
+        &repo_arg,
+        "--add-reviewer",
+        &reviewer_arg,
+    ])?;
+    Ok(())
+}
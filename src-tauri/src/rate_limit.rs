@@ -0,0 +1,147 @@
+//! Global rate limiting for external API calls.
+//!
+//! A token bucket per provider (Anthropic, OpenAI, GitHub) that every
+//! outbound call acquires from before running. Callers that arrive while the
+//! bucket is empty queue up and wait, so parallel agents + architect + TTS
+//! can't trigger provider bans.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    Anthropic,
+    OpenAi,
+    GitHub,
+}
+
+impl Provider {
+    const ALL: [Provider; 3] = [Provider::Anthropic, Provider::OpenAi, Provider::GitHub];
+
+    /// Bucket capacity (burst) and refill rate in tokens per second, kept
+    /// comfortably under each provider's published limits.
+    fn limits(self) -> (f64, f64) {
+        match self {
+            Provider::Anthropic => (10.0, 0.8),
+            Provider::OpenAi => (20.0, 2.0),
+            Provider::GitHub => (30.0, 1.0),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+    queued: u32,
+}
+
+impl Bucket {
+    fn new(provider: Provider) -> Self {
+        let (capacity, refill_per_sec) = provider.limits();
+        Self {
+            tokens: capacity,
+            capacity,
+            refill_per_sec,
+            last_refill: Instant::now(),
+            queued: 0,
+        }
+    }
+
+    fn refill(&mut self) {
+        let elapsed = self.last_refill.elapsed().as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = Instant::now();
+    }
+
+    /// Take a token if one is available, otherwise return how long to wait
+    /// before trying again.
+    fn try_take(&mut self) -> Result<(), Duration> {
+        self.refill();
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            let deficit = 1.0 - self.tokens;
+            Err(Duration::from_secs_f64(deficit / self.refill_per_sec))
+        }
+    }
+}
+
+static BUCKETS: Mutex<Option<[Bucket; 3]>> = Mutex::new(None);
+
+fn with_bucket<T>(provider: Provider, f: impl FnOnce(&mut Bucket) -> T) -> T {
+    let mut guard = BUCKETS.lock().unwrap();
+    let buckets = guard.get_or_insert_with(|| {
+        [
+            Bucket::new(Provider::Anthropic),
+            Bucket::new(Provider::OpenAi),
+            Bucket::new(Provider::GitHub),
+        ]
+    });
+    let index = Provider::ALL.iter().position(|p| *p == provider).unwrap();
+    f(&mut buckets[index])
+}
+
+/// Wait until the provider's bucket yields a token. Async callers (reqwest
+/// paths) queue here without blocking the runtime.
+pub async fn acquire(provider: Provider) {
+    loop {
+        let wait = match with_bucket(provider, |b| b.try_take()) {
+            Ok(()) => return,
+            Err(wait) => {
+                with_bucket(provider, |b| b.queued += 1);
+                wait
+            }
+        };
+        tokio::time::sleep(wait).await;
+        with_bucket(provider, |b| b.queued = b.queued.saturating_sub(1));
+    }
+}
+
+/// Blocking variant for synchronous call sites (gh / git subprocesses).
+pub fn acquire_blocking(provider: Provider) {
+    loop {
+        let wait = match with_bucket(provider, |b| b.try_take()) {
+            Ok(()) => return,
+            Err(wait) => {
+                with_bucket(provider, |b| b.queued += 1);
+                wait
+            }
+        };
+        std::thread::sleep(wait);
+        with_bucket(provider, |b| b.queued = b.queued.saturating_sub(1));
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitStatus {
+    pub provider: Provider,
+    pub available_tokens: f64,
+    pub capacity: f64,
+    pub queued: u32,
+}
+
+/// Current bucket levels for the debug panel.
+#[tauri::command]
+pub fn get_rate_limit_status() -> Result<Vec<RateLimitStatus>, String> {
+    Ok(Provider::ALL
+        .iter()
+        .map(|&provider| {
+            with_bucket(provider, |b| {
+                b.refill();
+                RateLimitStatus {
+                    provider,
+                    available_tokens: b.tokens,
+                    capacity: b.capacity,
+                    queued: b.queued,
+                }
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,328 @@
+//! Realtime voice proxy.
+//!
+//! Bridges the frontend's WebSocket to the OpenAI Realtime API so the API key
+//! never reaches the webview. Listens on localhost:9001. Connections must
+//! present the per-session token (fetched via `get_realtime_proxy_token`) and
+//! come from the app's own origin, so other local processes can't ride the
+//! user's key. The proxy also registers read-only query tools with the
+//! session ("what's the status of aidio?", "how much have I spent today?")
+//! and answers their function calls locally, so the model can speak live
+//! workspace state back.
+
+use std::sync::OnceLock;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+
+pub const PROXY_ADDR: &str = "127.0.0.1:9001";
+const REALTIME_URL: &str = "wss://api.openai.com/v1/realtime?model=gpt-4o-realtime-preview";
+
+/// Origins the webview may connect from: the Tauri shells plus the dev
+/// server from tauri.conf.json.
+const ALLOWED_ORIGINS: [&str; 3] = [
+    "tauri://localhost",
+    "http://tauri.localhost",
+    "http://localhost:3000",
+];
+
+static SESSION_TOKEN: OnceLock<String> = OnceLock::new();
+
+/// The connection token for this app run, minted on first use.
+pub fn session_token() -> &'static str {
+    SESSION_TOKEN.get_or_init(|| uuid::Uuid::new_v4().to_string())
+}
+
+/// Hand the frontend the token it must append to the proxy URL as
+/// `?token=...`.
+#[tauri::command]
+pub fn get_realtime_proxy_token() -> Result<String, String> {
+    Ok(session_token().to_string())
+}
+
+/// True when the request's query string carries the expected token.
+pub fn query_has_token(query: Option<&str>, expected: &str) -> bool {
+    let Some(query) = query else {
+        return false;
+    };
+    query
+        .split('&')
+        .any(|pair| pair.strip_prefix("token=") == Some(expected))
+}
+
+pub fn origin_allowed(origin: &str) -> bool {
+    ALLOWED_ORIGINS.contains(&origin)
+}
+
+/// Accept frontend connections forever, bridging each to OpenAI.
+pub async fn start_realtime_proxy(api_key: String) {
+    let listener = match TcpListener::bind(PROXY_ADDR).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Realtime proxy failed to bind {}: {}", PROXY_ADDR, e);
+            return;
+        }
+    };
+    log::info!("Realtime proxy listening on {}", PROXY_ADDR);
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let key = api_key.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, key).await {
+                        log::warn!("Realtime proxy connection error: {}", e);
+                    }
+                });
+            }
+            Err(e) => log::warn!("Realtime proxy accept error: {}", e),
+        }
+    }
+}
+
+/// Handshake gate: reject connections without the session token or from a
+/// foreign origin before the WebSocket upgrade completes.
+fn authorize(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    let deny = |reason: &str| {
+        log::warn!("Realtime proxy rejected a connection: {}", reason);
+        let mut denied = ErrorResponse::new(None);
+        *denied.status_mut() = tokio_tungstenite::tungstenite::http::StatusCode::UNAUTHORIZED;
+        denied
+    };
+    if !query_has_token(request.uri().query(), session_token()) {
+        return Err(deny("missing or wrong session token"));
+    }
+    let origin = request
+        .headers()
+        .get("Origin")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+    if !origin_allowed(origin) {
+        return Err(deny("disallowed origin"));
+    }
+    Ok(response)
+}
+
+async fn handle_connection(stream: TcpStream, api_key: String) -> Result<(), String> {
+    let client_ws = tokio_tungstenite::accept_hdr_async(stream, authorize)
+        .await
+        .map_err(|e| format!("WebSocket handshake failed: {}", e))?;
+
+    let mut request = REALTIME_URL
+        .into_client_request()
+        .map_err(|e| e.to_string())?;
+    request.headers_mut().insert(
+        "Authorization",
+        format!("Bearer {}", api_key).parse().map_err(|_| "bad key")?,
+    );
+    request
+        .headers_mut()
+        .insert("OpenAI-Beta", "realtime=v1".parse().unwrap());
+
+    let (upstream_ws, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| format!("Failed to reach OpenAI realtime: {}", e))?;
+
+    let (mut client_tx, mut client_rx) = client_ws.split();
+    let (mut upstream_tx, mut upstream_rx) = upstream_ws.split();
+
+    // Both directions (and tool-call answers) write upstream, so the writer
+    // is owned by one task fed through a channel.
+    let (upstream_send, mut upstream_queue) = tokio::sync::mpsc::unbounded_channel::<Message>();
+    let writer = async move {
+        while let Some(msg) = upstream_queue.recv().await {
+            if upstream_tx.send(msg).await.is_err() {
+                break;
+            }
+        }
+    };
+
+    // Register the workspace query tools with the session.
+    let session_update = json!({
+        "type": "session.update",
+        "session": { "tools": tool_definitions(), "tool_choice": "auto" },
+    });
+    let _ = upstream_send.send(Message::Text(session_update.to_string()));
+
+    let to_upstream = {
+        let upstream_send = upstream_send.clone();
+        async move {
+            while let Some(Ok(msg)) = client_rx.next().await {
+                if matches!(msg, Message::Close(_)) {
+                    break;
+                }
+                if upstream_send.send(msg).is_err() {
+                    break;
+                }
+            }
+        }
+    };
+    let to_client = async move {
+        while let Some(Ok(msg)) = upstream_rx.next().await {
+            if matches!(msg, Message::Close(_)) {
+                break;
+            }
+            if let Message::Text(text) = &msg {
+                record_response_usage(text);
+                if let Some(outputs) = intercept_tool_call(text).await {
+                    for output in outputs {
+                        if upstream_send.send(Message::Text(output)).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+            if client_tx.send(msg).await.is_err() {
+                break;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = writer => {}
+        _ = to_upstream => {}
+        _ = to_client => {}
+    }
+    Ok(())
+}
+
+/// Feed a completed response's token usage into the usage ledger so
+/// realtime voice sessions show up in cost reporting.
+fn record_response_usage(text: &str) {
+    if !text.contains("\"response.done\"") {
+        return;
+    }
+    let Ok(event) = serde_json::from_str::<Value>(text) else {
+        return;
+    };
+    if event.get("type").and_then(|t| t.as_str()) != Some("response.done") {
+        return;
+    }
+    let Some(usage) = event.pointer("/response/usage") else {
+        return;
+    };
+    let input = usage
+        .get("input_tokens")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    let output = usage
+        .get("output_tokens")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    let _ = crate::usage::record(
+        "openai",
+        "gpt-4o-realtime-preview",
+        crate::usage::UsageKind::Realtime,
+        input,
+        output,
+        None,
+    );
+}
+
+/// Read-only workspace tools exposed to the realtime session.
+fn tool_definitions() -> Value {
+    json!([
+        {
+            "type": "function",
+            "name": "list_projects",
+            "description": "List the user's tracked projects.",
+            "parameters": { "type": "object", "properties": {} },
+        },
+        {
+            "type": "function",
+            "name": "get_project_status",
+            "description": "Status of one project: specs, pending specs, active agents.",
+            "parameters": {
+                "type": "object",
+                "properties": { "project": { "type": "string", "description": "Project name" } },
+                "required": ["project"],
+            },
+        },
+        {
+            "type": "function",
+            "name": "get_spend_today",
+            "description": "How many API requests and tokens were used today.",
+            "parameters": { "type": "object", "properties": {} },
+        },
+    ])
+}
+
+/// If the upstream event is a completed function call for one of our tools,
+/// produce the messages (function output + response trigger) to inject.
+async fn intercept_tool_call(text: &str) -> Option<Vec<String>> {
+    let event: Value = serde_json::from_str(text).ok()?;
+    if event.get("type")?.as_str()? != "response.function_call_arguments.done" {
+        return None;
+    }
+    let name = event.get("name")?.as_str()?.to_string();
+    let call_id = event.get("call_id")?.as_str()?.to_string();
+    let arguments = event
+        .get("arguments")
+        .and_then(|a| a.as_str())
+        .unwrap_or("{}")
+        .to_string();
+
+    // Tool bodies shell out to gh / read the filesystem; keep them off the
+    // async pipe.
+    let output = tokio::task::spawn_blocking(move || run_query_tool(&name, &arguments))
+        .await
+        .ok()??;
+
+    Some(vec![
+        json!({
+            "type": "conversation.item.create",
+            "item": {
+                "type": "function_call_output",
+                "call_id": call_id,
+                "output": output,
+            },
+        })
+        .to_string(),
+        json!({ "type": "response.create" }).to_string(),
+    ])
+}
+
+/// Execute one of the read-only query tools. Returns `None` for tool names
+/// we don't own, so client-defined tools pass through untouched.
+fn run_query_tool(name: &str, arguments: &str) -> Option<String> {
+    match name {
+        "list_projects" => {
+            let projects = crate::commands::get_projects_blocking().ok()?;
+            if projects.is_empty() {
+                return Some("No tracked projects.".to_string());
+            }
+            let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+            Some(format!("Tracked projects: {}.", names.join(", ")))
+        }
+        "get_project_status" => {
+            let args: Value = serde_json::from_str(arguments).unwrap_or(Value::Null);
+            let wanted = args.get("project")?.as_str()?.to_lowercase();
+            let projects = crate::commands::get_projects_blocking().ok()?;
+            let project = projects
+                .iter()
+                .find(|p| p.name.to_lowercase().contains(&wanted))?;
+            let active = crate::agents::get_active_agents()
+                .map(|agents| agents.iter().filter(|a| a.project == project.name).count())
+                .unwrap_or(0);
+            Some(format!(
+                "{}: {} specs ({} pending), {} active agent{}.",
+                project.name,
+                project.spec_count,
+                project.pending_spec_count,
+                active,
+                if active == 1 { "" } else { "s" }
+            ))
+        }
+        "get_spend_today" => {
+            let usage = crate::usage::get_usage_analytics("today".to_string()).ok()?;
+            Some(format!(
+                "Today: {} requests, {} input tokens, {} output tokens.",
+                usage.total_requests, usage.total_input_tokens, usage.total_output_tokens
+            ))
+        }
+        _ => None,
+    }
+}
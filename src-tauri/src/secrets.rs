@@ -0,0 +1,120 @@
+//! API key storage in the OS keychain.
+//!
+//! Credentials live in the platform keychain (Keychain / DPAPI /
+//! secret-service via the `keyring` crate) instead of plaintext
+//! `settings.json`. `get_settings` hands the frontend redacted placeholders;
+//! real values only move through [`get_secret`] / [`set_secret`] and the
+//! internal loaders. Existing plaintext settings migrate on startup.
+
+use crate::settings;
+
+const SERVICE: &str = "sentra";
+
+/// Settings fields held in the keychain rather than on disk.
+pub const SECRET_KEYS: [&str; 6] = [
+    "openai_api_key",
+    "anthropic_api_key",
+    "github_token",
+    "gitlab_token",
+    "gitea_token",
+    "elevenlabs_api_key",
+];
+
+/// Placeholder returned in place of a stored secret.
+pub const REDACTED: &str = "********";
+
+fn entry(name: &str) -> Result<keyring::Entry, String> {
+    if !SECRET_KEYS.contains(&name) {
+        return Err(format!("Unknown secret: {}", name));
+    }
+    keyring::Entry::new(SERVICE, name).map_err(|e| e.to_string())
+}
+
+/// Read a secret. Missing entries are an empty string, not an error.
+pub fn get(name: &str) -> Result<String, String> {
+    match entry(name)?.get_password() {
+        Ok(value) => Ok(value),
+        Err(keyring::Error::NoEntry) => Ok(String::new()),
+        Err(e) => Err(format!("Keychain read failed: {}", e)),
+    }
+}
+
+/// Store a secret; an empty value deletes the entry.
+pub fn set(name: &str, value: &str) -> Result<(), String> {
+    raw_set(&entry(name)?, value)
+}
+
+#[tauri::command]
+pub fn get_secret(name: String) -> Result<String, String> {
+    get(&name)
+}
+
+#[tauri::command]
+pub fn set_secret(name: String, value: String) -> Result<(), String> {
+    set(&name, &value)
+}
+
+fn raw_set(entry: &keyring::Entry, value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        match entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(format!("Keychain delete failed: {}", e)),
+        }
+    } else {
+        entry
+            .set_password(value)
+            .map_err(|e| format!("Keychain write failed: {}", e))
+    }
+}
+
+fn scoped_entry(profile: &str, name: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(SERVICE, &format!("{}::{}", profile, name)).map_err(|e| e.to_string())
+}
+
+/// Copy the live credentials into a profile's scoped keychain entries.
+pub fn stash_profile(profile: &str) -> Result<(), String> {
+    for name in SECRET_KEYS {
+        raw_set(&scoped_entry(profile, name)?, &get(name)?)?;
+    }
+    Ok(())
+}
+
+/// Replace the live credentials with a profile's scoped keychain entries.
+/// Profiles that never stored a credential leave it empty.
+pub fn restore_profile(profile: &str) -> Result<(), String> {
+    for name in SECRET_KEYS {
+        let value = match scoped_entry(profile, name)?.get_password() {
+            Ok(value) => value,
+            Err(keyring::Error::NoEntry) => String::new(),
+            Err(e) => return Err(format!("Keychain read failed: {}", e)),
+        };
+        set(name, &value)?;
+    }
+    Ok(())
+}
+
+/// Move any plaintext credentials out of settings.json into the keychain.
+/// Fields only blank on disk after the keychain write succeeds, so a broken
+/// keychain (headless Linux without secret-service) loses nothing.
+pub fn migrate_plaintext_settings() -> Result<(), String> {
+    let mut loaded = settings::load_settings_raw()?;
+    let mut migrated = false;
+    for (name, value) in [
+        ("openai_api_key", &mut loaded.openai_api_key),
+        ("anthropic_api_key", &mut loaded.anthropic_api_key),
+        ("github_token", &mut loaded.github_token),
+        ("gitlab_token", &mut loaded.gitlab_token),
+        ("gitea_token", &mut loaded.gitea_token),
+        ("elevenlabs_api_key", &mut loaded.elevenlabs_api_key),
+    ] {
+        if !value.is_empty() && set(name, value).is_ok() {
+            value.clear();
+            migrated = true;
+        }
+    }
+    if migrated {
+        settings::write_settings(&loaded)?;
+        log::info!("Migrated plaintext credentials into the OS keychain");
+    }
+    Ok(())
+}
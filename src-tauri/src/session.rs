@@ -0,0 +1,70 @@
+//! Session state persistence.
+//!
+//! Snapshot of the UI's working state — open windows, the selected project,
+//! active agent streams, and per-view scroll positions — saved to
+//! `~/.claude/sentra/session.json` so a relaunch restores the user to exactly
+//! where they left off.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionState {
+    /// Labels of windows that were open (e.g. "main", "menubar").
+    #[serde(default)]
+    pub open_windows: Vec<String>,
+    /// Path of the project selected in the dashboard, if any.
+    #[serde(default)]
+    pub selected_project: Option<String>,
+    /// Agent ids whose log streams were being followed.
+    #[serde(default)]
+    pub active_streams: Vec<String>,
+    /// Scroll offset per view key (e.g. "dashboard", "diff:src/app.ts").
+    #[serde(default)]
+    pub scroll_positions: HashMap<String, f64>,
+    /// When this snapshot was written, RFC 3339.
+    #[serde(default)]
+    pub saved_at: String,
+}
+
+fn session_path() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("session.json"))
+}
+
+/// Load the last saved session, or a default empty one when no snapshot
+/// exists or the file is unreadable — a stale session should never block
+/// startup.
+pub fn load_session_state() -> SessionState {
+    let Ok(path) = session_path() else {
+        return SessionState::default();
+    };
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn get_session_state() -> Result<SessionState, String> {
+    Ok(load_session_state())
+}
+
+/// Persist the current session snapshot. The frontend calls this on window
+/// close and on significant navigation changes.
+#[tauri::command]
+pub fn save_session_state(mut state: SessionState) -> Result<(), String> {
+    state.saved_at = Utc::now().to_rfc3339();
+    let path = session_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
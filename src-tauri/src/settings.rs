@@ -0,0 +1,892 @@
+//! Settings persistence and voice notifications.
+//!
+//! Settings live as JSON at `~/.claude/sentra/settings.json`. Voice
+//! notifications go through the OpenAI TTS API and play via rodio.
+
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Version of the on-disk settings layout. Bump when a field is renamed or
+/// restructured (not when one is merely added with a serde default) and add
+/// a step to [`migrate_step`].
+pub const SETTINGS_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub openai_api_key: String,
+    #[serde(default)]
+    pub anthropic_api_key: String,
+    #[serde(default)]
+    pub github_token: String,
+    /// Git hosting provider: "github" (default), "gitlab", or "gitea".
+    /// Projects can override via `git_provider` in `.sentra/config.yml`.
+    #[serde(default = "default_git_provider")]
+    pub git_provider: String,
+    /// Base URL of the GitLab instance (gitlab.com or self-managed).
+    #[serde(default = "default_gitlab_url")]
+    pub gitlab_url: String,
+    #[serde(default)]
+    pub gitlab_token: String,
+    /// Base URL of the Gitea instance; empty disables the provider.
+    #[serde(default)]
+    pub gitea_url: String,
+    #[serde(default)]
+    pub gitea_token: String,
+    #[serde(default = "default_voice")]
+    pub voice: String,
+    #[serde(default = "default_true")]
+    pub voice_notifications_enabled: bool,
+    /// Fall back to the OS speech engine (say / SAPI / speech-dispatcher)
+    /// when OpenAI TTS is unavailable.
+    #[serde(default = "default_true")]
+    pub system_tts_fallback: bool,
+    /// Per-event-type overrides for voice message templates, keyed by
+    /// notification type (e.g. "agent_complete").
+    #[serde(default)]
+    pub notification_templates: std::collections::HashMap<String, String>,
+    /// TTS speed multiplier (0.25–4.0, passed to the TTS API).
+    #[serde(default = "default_speech_rate")]
+    pub speech_rate: f32,
+    /// Playback volume (0.0–1.0, applied on the rodio sink).
+    #[serde(default = "default_speech_volume")]
+    pub speech_volume: f32,
+    /// Output device name; `None` plays on the system default.
+    #[serde(default)]
+    pub audio_output_device: Option<String>,
+    /// TTS provider: "openai" (default) or "elevenlabs".
+    #[serde(default = "default_tts_provider")]
+    pub tts_provider: String,
+    #[serde(default)]
+    pub elevenlabs_api_key: String,
+    #[serde(default)]
+    pub elevenlabs_voice_id: String,
+    /// Global override for the architect system prompt; empty uses the
+    /// built-in one. Projects can override both via `.sentra/architect.md`.
+    /// `{project}` and `{stack}` placeholders are substituted.
+    #[serde(default)]
+    pub architect_system_prompt: String,
+    /// LLM provider for the architect: "anthropic" (default), "openai", or
+    /// "ollama". Projects can override via `llm_provider` in
+    /// `.sentra/config.yml`.
+    #[serde(default = "default_llm_provider")]
+    pub llm_provider: String,
+    /// Model for the OpenAI chat provider.
+    #[serde(default = "default_openai_chat_model")]
+    pub openai_chat_model: String,
+    /// Base URL of the local Ollama server.
+    #[serde(default = "default_ollama_url")]
+    pub ollama_url: String,
+    /// Model for the Ollama provider.
+    #[serde(default = "default_ollama_model")]
+    pub ollama_model: String,
+    /// Path to a whisper.cpp CLI binary for offline transcription; empty
+    /// disables the fallback.
+    #[serde(default)]
+    pub whisper_cpp_path: String,
+    /// Path to the downloaded ggml model whisper.cpp should load.
+    #[serde(default)]
+    pub whisper_model_path: String,
+    /// Time windows during which notifications are deferred and summarized
+    /// afterwards instead of spoken immediately.
+    #[serde(default)]
+    pub quiet_hours: Vec<QuietWindow>,
+    /// Cap on locally-run agents across all projects; further starts queue.
+    #[serde(default = "default_max_concurrent_agents")]
+    pub max_concurrent_agents: u32,
+    /// Cap on locally-run agents within one project.
+    #[serde(default = "default_max_agents_per_project")]
+    pub max_agents_per_project: u32,
+    /// Global log level: off/error/warn/info/debug/trace.
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+    /// Per-module level overrides keyed by module prefix (e.g.
+    /// `sentra_lib::watcher`).
+    #[serde(default)]
+    pub log_levels: std::collections::HashMap<String, String>,
+    /// Calendar-month spend budget in USD.
+    #[serde(default = "default_monthly_budget")]
+    pub monthly_budget: f64,
+    /// Fraction of the budget (0.0–1.0) at which a warning fires.
+    #[serde(default = "default_budget_alert_threshold")]
+    pub budget_alert_threshold: f64,
+    /// Refuse to start new local agents once the budget is exceeded.
+    #[serde(default)]
+    pub block_agents_over_budget: bool,
+    /// Port for the embedded GitHub webhook listener; 0 disables it.
+    #[serde(default)]
+    pub webhook_port: u16,
+    /// Shared secret GitHub signs webhook payloads with. Empty skips
+    /// signature verification.
+    #[serde(default)]
+    pub webhook_secret: String,
+    /// Directory names the file watcher never descends into or reports
+    /// events from.
+    #[serde(default = "default_watcher_ignore")]
+    pub watcher_ignore: Vec<String>,
+}
+
+/// One quiet-hours window in local time. Windows that end before they start
+/// (e.g. 22:00–07:00) wrap past midnight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietWindow {
+    /// Days the window starts on: "mon".."sun". Empty means every day.
+    #[serde(default)]
+    pub days: Vec<String>,
+    /// Start time as "HH:MM".
+    pub start: String,
+    /// End time as "HH:MM".
+    pub end: String,
+}
+
+fn default_schema_version() -> u32 {
+    SETTINGS_SCHEMA_VERSION
+}
+
+fn default_monthly_budget() -> f64 {
+    100.0
+}
+
+fn default_budget_alert_threshold() -> f64 {
+    0.8
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_max_concurrent_agents() -> u32 {
+    3
+}
+
+fn default_max_agents_per_project() -> u32 {
+    1
+}
+
+fn default_llm_provider() -> String {
+    "anthropic".to_string()
+}
+
+fn default_git_provider() -> String {
+    "github".to_string()
+}
+
+fn default_gitlab_url() -> String {
+    "https://gitlab.com".to_string()
+}
+
+fn default_watcher_ignore() -> Vec<String> {
+    ["node_modules", "target", ".git"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_openai_chat_model() -> String {
+    "gpt-4o".to_string()
+}
+
+fn default_ollama_url() -> String {
+    "http://localhost:11434".to_string()
+}
+
+fn default_ollama_model() -> String {
+    "llama3.1".to_string()
+}
+
+fn default_tts_provider() -> String {
+    "openai".to_string()
+}
+
+fn default_speech_rate() -> f32 {
+    1.0
+}
+
+fn default_speech_volume() -> f32 {
+    1.0
+}
+
+fn default_voice() -> String {
+    "nova".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            schema_version: SETTINGS_SCHEMA_VERSION,
+            openai_api_key: String::new(),
+            anthropic_api_key: String::new(),
+            github_token: String::new(),
+            git_provider: default_git_provider(),
+            gitlab_url: default_gitlab_url(),
+            gitlab_token: String::new(),
+            gitea_url: String::new(),
+            gitea_token: String::new(),
+            voice: default_voice(),
+            voice_notifications_enabled: true,
+            system_tts_fallback: true,
+            notification_templates: std::collections::HashMap::new(),
+            speech_rate: default_speech_rate(),
+            speech_volume: default_speech_volume(),
+            audio_output_device: None,
+            tts_provider: default_tts_provider(),
+            elevenlabs_api_key: String::new(),
+            elevenlabs_voice_id: String::new(),
+            architect_system_prompt: String::new(),
+            llm_provider: default_llm_provider(),
+            openai_chat_model: default_openai_chat_model(),
+            ollama_url: default_ollama_url(),
+            ollama_model: default_ollama_model(),
+            whisper_cpp_path: String::new(),
+            whisper_model_path: String::new(),
+            quiet_hours: Vec::new(),
+            max_concurrent_agents: default_max_concurrent_agents(),
+            max_agents_per_project: default_max_agents_per_project(),
+            log_level: default_log_level(),
+            log_levels: std::collections::HashMap::new(),
+            monthly_budget: default_monthly_budget(),
+            budget_alert_threshold: default_budget_alert_threshold(),
+            block_agents_over_budget: false,
+            webhook_port: 0,
+            webhook_secret: String::new(),
+            watcher_ignore: default_watcher_ignore(),
+        }
+    }
+}
+
+pub fn sentra_dir() -> Result<PathBuf, String> {
+    Ok(crate::commands::claude_home()?.join("sentra"))
+}
+
+pub fn settings_path() -> Result<PathBuf, String> {
+    Ok(sentra_dir()?.join("settings.json"))
+}
+
+/// Load settings as written on disk, without touching the keychain. Used by
+/// the plaintext-credential migration; everything else wants
+/// [`load_settings`].
+pub fn load_settings_raw() -> Result<Settings, String> {
+    let path = settings_path()?;
+    if !path.exists() {
+        return Ok(Settings::default());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let mut value: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid settings file: {}", e))?;
+    let upgraded = migrate_settings_value(&mut value);
+    let settings: Settings =
+        serde_json::from_value(value).map_err(|e| format!("Invalid settings file: {}", e))?;
+    if upgraded {
+        // Persist the upgraded layout so migrations run once, not per load.
+        write_settings(&settings)?;
+    }
+    Ok(settings)
+}
+
+/// Upgrade an on-disk settings document to the current schema, one version
+/// step at a time. Returns whether anything changed. Files written by a
+/// newer release are left untouched.
+pub fn migrate_settings_value(value: &mut serde_json::Value) -> bool {
+    // Files that predate versioning are schema 1.
+    let had_version = value.get("schemaVersion").is_some();
+    let mut version = value
+        .get("schemaVersion")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1) as u32;
+    if version > SETTINGS_SCHEMA_VERSION {
+        log::warn!(
+            "Settings file has schema {} but this build supports {}; leaving it as-is",
+            version,
+            SETTINGS_SCHEMA_VERSION
+        );
+        return false;
+    }
+    let initial = version;
+    while version < SETTINGS_SCHEMA_VERSION {
+        migrate_step(value, version);
+        version += 1;
+    }
+    value["schemaVersion"] = serde_json::json!(SETTINGS_SCHEMA_VERSION);
+    version != initial || !had_version
+}
+
+/// Apply the migration from `from` to `from + 1`.
+fn migrate_step(value: &mut serde_json::Value, from: u32) {
+    #[allow(clippy::single_match)]
+    match from {
+        // v1 → v2: quiet hours were a single "quietHoursStart"/"quietHoursEnd"
+        // pair; they became the "quietHours" window list.
+        1 => {
+            let start = value.get("quietHoursStart").and_then(|v| v.as_str());
+            let end = value.get("quietHoursEnd").and_then(|v| v.as_str());
+            if let (Some(start), Some(end)) = (start, end) {
+                value["quietHours"] = serde_json::json!([{
+                    "days": [],
+                    "start": start,
+                    "end": end,
+                }]);
+            }
+            if let Some(obj) = value.as_object_mut() {
+                obj.remove("quietHoursStart");
+                obj.remove("quietHoursEnd");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Load settings from disk, falling back to defaults when the file is
+/// missing or unreadable. Credential fields are hydrated from the OS
+/// keychain; the JSON file only holds them pre-migration.
+pub fn load_settings() -> Result<Settings, String> {
+    let mut settings = load_settings_raw()?;
+    for (name, value) in [
+        ("openai_api_key", &mut settings.openai_api_key),
+        ("anthropic_api_key", &mut settings.anthropic_api_key),
+        ("github_token", &mut settings.github_token),
+        ("gitlab_token", &mut settings.gitlab_token),
+        ("gitea_token", &mut settings.gitea_token),
+        ("elevenlabs_api_key", &mut settings.elevenlabs_api_key),
+    ] {
+        if value.is_empty() {
+            *value = crate::secrets::get(name).unwrap_or_default();
+        }
+    }
+    Ok(settings)
+}
+
+/// Write settings to disk. Credential fields are stripped first; they belong
+/// to the keychain, not the JSON file.
+pub fn write_settings(settings: &Settings) -> Result<(), String> {
+    let path = settings_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let mut on_disk = settings.clone();
+    on_disk.openai_api_key.clear();
+    on_disk.anthropic_api_key.clear();
+    on_disk.github_token.clear();
+    on_disk.gitlab_token.clear();
+    on_disk.gitea_token.clear();
+    on_disk.elevenlabs_api_key.clear();
+    let json = serde_json::to_string_pretty(&on_disk).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_settings() -> Result<Settings, String> {
+    let mut settings = load_settings()?;
+    // The frontend never sees stored credentials, only whether one exists.
+    for value in [
+        &mut settings.openai_api_key,
+        &mut settings.anthropic_api_key,
+        &mut settings.github_token,
+        &mut settings.gitlab_token,
+        &mut settings.gitea_token,
+        &mut settings.elevenlabs_api_key,
+    ] {
+        if !value.is_empty() {
+            *value = crate::secrets::REDACTED.to_string();
+        }
+    }
+    Ok(settings)
+}
+
+#[tauri::command]
+pub fn save_settings(app: tauri::AppHandle, settings: Settings) -> Result<(), String> {
+    let previous_key = load_settings().map(|s| s.openai_api_key).unwrap_or_default();
+    // Credential fields round-trip through get_settings as placeholders;
+    // only values the user actually changed reach the keychain.
+    for (name, value) in [
+        ("openai_api_key", &settings.openai_api_key),
+        ("anthropic_api_key", &settings.anthropic_api_key),
+        ("github_token", &settings.github_token),
+        ("gitlab_token", &settings.gitlab_token),
+        ("gitea_token", &settings.gitea_token),
+        ("elevenlabs_api_key", &settings.elevenlabs_api_key),
+    ] {
+        if value != crate::secrets::REDACTED {
+            crate::secrets::set(name, value)?;
+        }
+    }
+    write_settings(&settings)?;
+    // Log level changes apply immediately.
+    crate::logging::reload_levels();
+    // A changed OpenAI key (including one added after launch) restarts the
+    // realtime proxy; a cleared key stops it.
+    let current_key = load_settings().map(|s| s.openai_api_key).unwrap_or_default();
+    if current_key != previous_key {
+        crate::lifecycle::apply_proxy_key(&app, current_key);
+    }
+    Ok(())
+}
+
+fn profiles_dir() -> Result<PathBuf, String> {
+    Ok(sentra_dir()?.join("profiles"))
+}
+
+fn active_profile_file() -> Result<PathBuf, String> {
+    Ok(sentra_dir()?.join("active-profile.txt"))
+}
+
+/// Name of the settings profile currently live in settings.json.
+pub fn active_profile_name() -> String {
+    active_profile_file()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// Profile names may become file names, so keep them boring.
+fn validate_profile_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Invalid profile name: {:?} (use letters, digits, - and _)",
+            name
+        ));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn active_profile() -> Result<String, String> {
+    Ok(active_profile_name())
+}
+
+/// Every known profile: saved snapshots plus the active one.
+#[tauri::command]
+pub fn list_profiles() -> Result<Vec<String>, String> {
+    let mut names = vec![active_profile_name()];
+    if let Ok(entries) = fs::read_dir(profiles_dir()?) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().map_or(false, |e| e == "json") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+/// Snapshot the live settings under the current profile, then load the named
+/// one (created from defaults if new). Credentials swap through
+/// profile-scoped keychain entries so each profile keeps its own keys.
+#[tauri::command]
+pub fn switch_profile(name: String) -> Result<Settings, String> {
+    validate_profile_name(&name)?;
+    let current = active_profile_name();
+    if current == name {
+        return get_settings();
+    }
+
+    // Save the outgoing profile before anything else can fail.
+    let dir = profiles_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let live = load_settings_raw()?;
+    let json = serde_json::to_string_pretty(&live).map_err(|e| e.to_string())?;
+    fs::write(dir.join(format!("{}.json", current)), json).map_err(|e| e.to_string())?;
+    crate::secrets::stash_profile(&current)?;
+
+    let target_path = dir.join(format!("{}.json", name));
+    let incoming = if target_path.exists() {
+        let content = fs::read_to_string(&target_path).map_err(|e| e.to_string())?;
+        let mut value: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| format!("Invalid profile {}: {}", name, e))?;
+        migrate_settings_value(&mut value);
+        serde_json::from_value(value).map_err(|e| format!("Invalid profile {}: {}", name, e))?
+    } else {
+        Settings::default()
+    };
+    write_settings(&incoming)?;
+    crate::secrets::restore_profile(&name)?;
+    fs::write(active_profile_file()?, &name).map_err(|e| e.to_string())?;
+
+    crate::logging::reload_levels();
+    get_settings()
+}
+
+/// Validation result for one configured credential or tool.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+    /// "openai", "anthropic", "github_token", or "gh_cli".
+    pub id: String,
+    pub label: String,
+    pub configured: bool,
+    /// `None` when the credential isn't configured, so nothing was checked.
+    pub valid: Option<bool>,
+    /// Error message or extra info (e.g. granted token scopes).
+    pub detail: Option<String>,
+}
+
+impl CredentialStatus {
+    fn unconfigured(id: &str, label: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            label: label.to_string(),
+            configured: false,
+            valid: None,
+            detail: None,
+        }
+    }
+
+    fn checked(id: &str, label: &str, result: Result<Option<String>, String>) -> Self {
+        let (valid, detail) = match result {
+            Ok(detail) => (true, detail),
+            Err(message) => (false, Some(message)),
+        };
+        Self {
+            id: id.to_string(),
+            label: label.to_string(),
+            configured: true,
+            valid: Some(valid),
+            detail,
+        }
+    }
+}
+
+/// Probe every configured credential with a cheap live request, so a bad key
+/// shows up in the settings panel instead of mid voice call.
+#[tauri::command]
+pub async fn validate_settings() -> Result<Vec<CredentialStatus>, String> {
+    let loaded = load_settings()?;
+    let mut statuses = Vec::new();
+
+    if loaded.openai_api_key.is_empty() {
+        statuses.push(CredentialStatus::unconfigured("openai", "OpenAI API key"));
+    } else {
+        statuses.push(CredentialStatus::checked(
+            "openai",
+            "OpenAI API key",
+            check_openai_key(&loaded.openai_api_key).await,
+        ));
+    }
+
+    if loaded.anthropic_api_key.is_empty() {
+        statuses.push(CredentialStatus::unconfigured(
+            "anthropic",
+            "Anthropic API key",
+        ));
+    } else {
+        statuses.push(CredentialStatus::checked(
+            "anthropic",
+            "Anthropic API key",
+            check_anthropic_key(&loaded.anthropic_api_key).await,
+        ));
+    }
+
+    if loaded.github_token.is_empty() {
+        statuses.push(CredentialStatus::unconfigured(
+            "github_token",
+            "GitHub token",
+        ));
+    } else {
+        statuses.push(CredentialStatus::checked(
+            "github_token",
+            "GitHub token",
+            check_github_token(&loaded.github_token).await,
+        ));
+    }
+
+    statuses.push(CredentialStatus::checked(
+        "gh_cli",
+        "gh CLI",
+        check_gh_cli(),
+    ));
+
+    Ok(statuses)
+}
+
+/// Cheapest authenticated OpenAI endpoint: list models.
+async fn check_openai_key(api_key: &str) -> Result<Option<String>, String> {
+    crate::rate_limit::acquire(crate::rate_limit::Provider::OpenAi).await;
+    let response = reqwest::Client::new()
+        .get("https://api.openai.com/v1/models")
+        .bearer_auth(api_key)
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    if response.status().is_success() {
+        Ok(None)
+    } else {
+        Err(format!("OpenAI API error: {}", response.status()))
+    }
+}
+
+/// Minimal one-token message against the small model.
+async fn check_anthropic_key(api_key: &str) -> Result<Option<String>, String> {
+    crate::rate_limit::acquire(crate::rate_limit::Provider::Anthropic).await;
+    let response = reqwest::Client::new()
+        .post("https://api.anthropic.com/v1/messages")
+        .header("x-api-key", api_key)
+        .header("anthropic-version", "2023-06-01")
+        .json(&serde_json::json!({
+            "model": "claude-3-5-haiku-20241022",
+            "max_tokens": 1,
+            "messages": [{ "role": "user", "content": "ping" }],
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    if response.status().is_success() {
+        Ok(None)
+    } else {
+        Err(format!("Anthropic API error: {}", response.status()))
+    }
+}
+
+/// Validate the token against /user and surface its granted scopes.
+async fn check_github_token(token: &str) -> Result<Option<String>, String> {
+    let response = reqwest::Client::new()
+        .get("https://api.github.com/user")
+        .bearer_auth(token)
+        .header("User-Agent", "sentra")
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("GitHub API error: {}", response.status()));
+    }
+    let scopes = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    if scopes.is_empty() {
+        // Fine-grained tokens don't report classic scopes.
+        return Ok(None);
+    }
+    if !scopes.split(", ").any(|s| s == "repo") {
+        return Err(format!(
+            "Token lacks the \"repo\" scope (granted: {})",
+            scopes
+        ));
+    }
+    Ok(Some(format!("Scopes: {}", scopes)))
+}
+
+fn check_gh_cli() -> Result<Option<String>, String> {
+    let output = std::process::Command::new("gh")
+        .args(["auth", "status"])
+        .output()
+        .map_err(|_| "gh CLI not installed".to_string())?;
+    if output.status.success() {
+        Ok(None)
+    } else {
+        Err("gh is installed but not authenticated (run `gh auth login`)".to_string())
+    }
+}
+
+/// Queue a notification for speech. Playback is serialized through the
+/// global speech queue so concurrent calls don't overlap audio.
+#[tauri::command]
+pub async fn speak_notification(
+    message: String,
+    priority: Option<crate::speech::SpeechPriority>,
+) -> Result<usize, String> {
+    let settings = load_settings()?;
+    if !settings.voice_notifications_enabled {
+        return Ok(0);
+    }
+    Ok(crate::speech::enqueue(
+        message,
+        priority.unwrap_or(crate::speech::SpeechPriority::Normal),
+    ))
+}
+
+/// Cap on the on-disk audio cache before old entries are evicted.
+const AUDIO_CACHE_MAX_BYTES: u64 = 50 * 1024 * 1024;
+
+fn audio_cache_dir() -> Result<PathBuf, String> {
+    Ok(sentra_dir()?.join("audio-cache"))
+}
+
+/// Stable FNV-1a hash so cache keys survive restarts (std's default hasher
+/// is randomly seeded per process).
+pub fn stable_hash(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn cache_file_for(voice: &str, text: &str, speed: f32) -> Result<PathBuf, String> {
+    // Speed is part of the key since the synthesized audio differs.
+    let key = format!("{}|{}", speed, text);
+    Ok(audio_cache_dir()?.join(format!("{}-{:016x}.mp3", voice, stable_hash(&key))))
+}
+
+pub(crate) fn read_cached_audio(voice: &str, text: &str, speed: f32) -> Option<Vec<u8>> {
+    fs::read(cache_file_for(voice, text, speed).ok()?).ok()
+}
+
+pub(crate) fn write_cached_audio(
+    voice: &str,
+    text: &str,
+    speed: f32,
+    audio: &[u8],
+) -> Result<(), String> {
+    let dir = audio_cache_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    fs::write(cache_file_for(voice, text, speed)?, audio).map_err(|e| e.to_string())?;
+    evict_audio_cache(&dir);
+    Ok(())
+}
+
+/// Delete oldest cache entries until the cache fits the size budget.
+fn evict_audio_cache(dir: &std::path::Path) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    let mut files: Vec<(PathBuf, std::time::SystemTime, u64)> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let meta = e.metadata().ok()?;
+            Some((e.path(), meta.modified().ok()?, meta.len()))
+        })
+        .collect();
+    let mut total: u64 = files.iter().map(|(_, _, len)| len).sum();
+    if total <= AUDIO_CACHE_MAX_BYTES {
+        return;
+    }
+    files.sort_by_key(|(_, modified, _)| *modified);
+    for (path, _, len) in files {
+        if total <= AUDIO_CACHE_MAX_BYTES {
+            break;
+        }
+        if fs::remove_file(&path).is_ok() {
+            total = total.saturating_sub(len);
+        }
+    }
+}
+
+/// Call the OpenAI TTS API and return the mp3 bytes.
+pub async fn fetch_tts_audio(
+    api_key: &str,
+    voice: &str,
+    text: &str,
+    speed: f32,
+) -> Result<Vec<u8>, String> {
+    crate::rate_limit::acquire(crate::rate_limit::Provider::OpenAi).await;
+    let client = reqwest::Client::new();
+    let response = client
+        .post("https://api.openai.com/v1/audio/speech")
+        .bearer_auth(api_key)
+        .json(&serde_json::json!({
+            "model": "tts-1",
+            "voice": voice,
+            "input": text,
+            "speed": speed.clamp(0.25, 4.0),
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("TTS request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("TTS API error: {}", response.status()));
+    }
+    // TTS bills per input character; record characters as input tokens.
+    let _ = crate::usage::record(
+        "openai",
+        "tts-1",
+        crate::usage::UsageKind::Tts,
+        text.chars().count() as u64,
+        0,
+        None,
+    );
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+    Ok(bytes.to_vec())
+}
+
+/// Decode and play an mp3 buffer, honoring the configured volume and output
+/// device. Blocks its (worker) thread until playback finishes or the sink is
+/// stopped via [`stop_current_playback`].
+pub fn play_audio_cross_platform(
+    audio: Vec<u8>,
+    volume: f32,
+    device_name: Option<&str>,
+) -> Result<(), String> {
+    let (_stream, handle) = open_output_stream(device_name)?;
+    let sink = std::sync::Arc::new(rodio::Sink::try_new(&handle).map_err(|e| e.to_string())?);
+    sink.set_volume(volume.clamp(0.0, 1.0));
+    let source = rodio::Decoder::new(Cursor::new(audio)).map_err(|e| e.to_string())?;
+    sink.append(source);
+
+    // Publish the sink so stop_speaking can cancel mid-announcement.
+    *CURRENT_SINK.lock().unwrap() = Some(sink.clone());
+    sink.sleep_until_end();
+    *CURRENT_SINK.lock().unwrap() = None;
+    Ok(())
+}
+
+static CURRENT_SINK: std::sync::Mutex<Option<std::sync::Arc<rodio::Sink>>> =
+    std::sync::Mutex::new(None);
+
+/// Stop whatever is currently playing, if anything. Returns whether playback
+/// was interrupted.
+pub fn stop_current_playback() -> bool {
+    match CURRENT_SINK.lock().unwrap().take() {
+        Some(sink) => {
+            sink.stop();
+            true
+        }
+        None => false,
+    }
+}
+
+fn open_output_stream(
+    device_name: Option<&str>,
+) -> Result<(rodio::OutputStream, rodio::OutputStreamHandle), String> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    if let Some(name) = device_name {
+        let host = rodio::cpal::default_host();
+        let device = host
+            .output_devices()
+            .map_err(|e| e.to_string())?
+            .find(|d| d.name().map_or(false, |n| n == name));
+        if let Some(device) = device {
+            return rodio::OutputStream::try_from_device(&device)
+                .map_err(|e| format!("Failed to open device {}: {}", name, e));
+        }
+        // Configured device unplugged: fall through to the default rather
+        // than going silent.
+        log::warn!("Audio device \"{}\" not found; using default", name);
+    }
+    rodio::OutputStream::try_default().map_err(|e| format!("No audio output: {}", e))
+}
+
+/// Names of the available audio output devices for the settings panel.
+#[tauri::command]
+pub fn list_audio_output_devices() -> Result<Vec<String>, String> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    let host = rodio::cpal::default_host();
+    Ok(host
+        .output_devices()
+        .map_err(|e| e.to_string())?
+        .filter_map(|d| d.name().ok())
+        .collect())
+}
@@ -0,0 +1,948 @@
+//! Spec storage and lifecycle.
+//!
+//! Specs are versioned markdown files under `<project>/.sentra/specs/`, named
+//! `<id>-v<version>.md`. Per-spec metadata JSON lives alongside them in
+//! `.sentra/specs/.metadata/<id>.json`. Specs awaiting architect review sit
+//! in `.sentra/specs/pending/`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Per-spec advisory locks serializing read-modify-write cycles on metadata,
+/// so a voice flow and a manual edit saving concurrently can't interleave.
+static SPEC_LOCKS: Mutex<Option<HashMap<String, Arc<Mutex<()>>>>> = Mutex::new(None);
+
+fn spec_lock(project_path: &Path, id: &str) -> Arc<Mutex<()>> {
+    let key = format!("{}::{}", project_path.display(), id);
+    let mut locks = SPEC_LOCKS.lock().unwrap();
+    locks
+        .get_or_insert_with(HashMap::new)
+        .entry(key)
+        .or_insert_with(|| Arc::new(Mutex::new(())))
+        .clone()
+}
+
+/// Lifecycle state of a spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecStatus {
+    Draft,
+    InReview,
+    Approved,
+    Implemented,
+    Abandoned,
+}
+
+impl SpecStatus {
+    /// Allowed transitions. Abandoning is possible from any live state and
+    /// an abandoned spec can be revived back to draft.
+    pub fn can_transition_to(self, next: SpecStatus) -> bool {
+        use SpecStatus::*;
+        matches!(
+            (self, next),
+            (Draft, InReview)
+                | (InReview, Draft)
+                // Solo users approve straight from draft.
+                | (Draft | InReview, Approved)
+                | (Approved, Implemented)
+                | (Draft | InReview | Approved, Abandoned)
+                | (Abandoned, Draft)
+        )
+    }
+}
+
+fn default_spec_status() -> SpecStatus {
+    SpecStatus::Draft
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecMetadata {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub approved: bool,
+    /// Lifecycle status; `approved` is kept in sync for older callers.
+    #[serde(default = "default_spec_status")]
+    pub status: SpecStatus,
+    pub version: u32,
+    #[serde(default)]
+    pub issue_url: Option<String>,
+    /// Issues created from this spec, in creation order.
+    #[serde(default)]
+    pub issue_urls: Vec<String>,
+    /// Numbers of the linked issues, parallel to `issue_urls`.
+    #[serde(default)]
+    pub issue_numbers: Vec<u64>,
+}
+
+/// Listing entry returned to the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecInfo {
+    pub id: String,
+    pub title: String,
+    pub updated_at: String,
+    pub approved: bool,
+    pub status: SpecStatus,
+    pub version: u32,
+}
+
+pub fn specs_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".sentra").join("specs")
+}
+
+pub fn metadata_dir(project_path: &Path) -> PathBuf {
+    specs_dir(project_path).join(".metadata")
+}
+
+pub fn pending_specs_dir(project_path: &Path) -> Result<PathBuf, String> {
+    Ok(specs_dir(project_path).join("pending"))
+}
+
+pub fn spec_file(project_path: &Path, id: &str, version: u32) -> PathBuf {
+    specs_dir(project_path).join(format!("{}-v{}.md", id, version))
+}
+
+fn metadata_path(project_path: &Path, id: &str) -> PathBuf {
+    metadata_dir(project_path).join(format!("{}.json", id))
+}
+
+pub fn read_metadata(project_path: &Path, id: &str) -> Result<SpecMetadata, String> {
+    let path = metadata_path(project_path, id);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read spec metadata {}: {}", id, e))?;
+    let mut meta: SpecMetadata = match serde_json::from_str(&content) {
+        Ok(meta) => meta,
+        // A truncated file (crash mid-write before writes were atomic)
+        // shouldn't orphan the spec; rebuild what we can from the markdown.
+        Err(_) => {
+            log::warn!("Spec metadata {} is corrupt; rebuilding from spec files", id);
+            let rebuilt = rebuild_metadata(project_path, id)?;
+            write_metadata(project_path, &rebuilt)?;
+            rebuilt
+        }
+    };
+    // Metadata written before the status field only has the approved flag.
+    if meta.approved && meta.status == SpecStatus::Draft {
+        meta.status = SpecStatus::Approved;
+    }
+    Ok(meta)
+}
+
+/// Reconstruct metadata from the `<id>-v<N>.md` files on disk: latest
+/// version, title from the first heading, timestamps from file mtimes.
+/// Approval state is unrecoverable and resets to draft.
+fn rebuild_metadata(project_path: &Path, id: &str) -> Result<SpecMetadata, String> {
+    let mut version = 0;
+    while spec_file(project_path, id, version + 1).exists() {
+        version += 1;
+    }
+    if version == 0 {
+        return Err(format!("Spec {} has no versions on disk to rebuild from", id));
+    }
+
+    let latest = spec_file(project_path, id, version);
+    let content = fs::read_to_string(&latest).map_err(|e| e.to_string())?;
+    let title = content
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|t| t.trim().to_string())
+        .unwrap_or_else(|| id.to_string());
+
+    let mtime = |path: &Path| -> String {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .map(|t| chrono::DateTime::<Utc>::from(t).to_rfc3339())
+            .unwrap_or_else(|_| Utc::now().to_rfc3339())
+    };
+
+    Ok(SpecMetadata {
+        id: id.to_string(),
+        title,
+        created_at: mtime(&spec_file(project_path, id, 1)),
+        updated_at: mtime(&latest),
+        approved: false,
+        status: SpecStatus::Draft,
+        version,
+        issue_url: None,
+        issue_urls: Vec::new(),
+        issue_numbers: Vec::new(),
+    })
+}
+
+/// Write metadata atomically: to a temp file in the same directory, then a
+/// rename over the target, so readers never see a half-written JSON.
+pub fn write_metadata(project_path: &Path, meta: &SpecMetadata) -> Result<(), String> {
+    let dir = metadata_dir(project_path);
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(meta).map_err(|e| e.to_string())?;
+    let target = metadata_path(project_path, &meta.id);
+    let temp = dir.join(format!("{}.json.tmp", meta.id));
+    fs::write(&temp, json).map_err(|e| e.to_string())?;
+    fs::rename(&temp, &target).map_err(|e| e.to_string())
+}
+
+/// All spec metadata for a project, sorted newest first.
+pub fn list_specs_for_project(project_path: &Path) -> Result<Vec<SpecInfo>, String> {
+    let dir = metadata_dir(project_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut infos = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        if entry.path().extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let content = fs::read_to_string(entry.path()).map_err(|e| e.to_string())?;
+        if let Ok(meta) = serde_json::from_str::<SpecMetadata>(&content) {
+            let status = if meta.approved && meta.status == SpecStatus::Draft {
+                SpecStatus::Approved
+            } else {
+                meta.status
+            };
+            infos.push(SpecInfo {
+                id: meta.id,
+                title: meta.title,
+                updated_at: meta.updated_at,
+                approved: meta.approved,
+                status,
+                version: meta.version,
+            });
+        }
+    }
+    infos.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(infos)
+}
+
+#[tauri::command]
+pub fn list_specs(project_path: String) -> Result<Vec<SpecInfo>, String> {
+    list_specs_for_project(Path::new(&project_path))
+}
+
+/// Spec ids present as `<id>-v<N>.md` files on disk.
+fn spec_ids_on_disk(project_path: &Path) -> Vec<String> {
+    let mut ids = Vec::new();
+    let Ok(entries) = fs::read_dir(specs_dir(project_path)) else {
+        return ids;
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        // Strip the trailing -v<N> to get the id.
+        let Some(dash) = stem.rfind("-v") else { continue };
+        if !stem[dash + 2..].chars().all(|c| c.is_ascii_digit()) || stem[dash + 2..].is_empty() {
+            continue;
+        }
+        let id = stem[..dash].to_string();
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Regenerate missing or corrupt metadata from the spec files on disk, so
+/// specs reappear in the UI after `.metadata/` is deleted or mangled. Valid
+/// metadata (which carries approval state) is left untouched.
+#[tauri::command]
+pub fn reindex_specs(project_path: String) -> Result<Vec<SpecInfo>, String> {
+    let path = Path::new(&project_path);
+    let mut rebuilt = 0;
+    for id in spec_ids_on_disk(path) {
+        let lock = spec_lock(path, &id);
+        let _guard = lock.lock().unwrap();
+        let valid = fs::read_to_string(metadata_path(path, &id))
+            .ok()
+            .and_then(|content| serde_json::from_str::<SpecMetadata>(&content).ok())
+            .is_some();
+        if !valid {
+            write_metadata(path, &rebuild_metadata(path, &id)?)?;
+            rebuilt += 1;
+        }
+    }
+    if rebuilt > 0 {
+        log::info!("Reindexed {} spec(s) in {}", rebuilt, project_path);
+    }
+    list_specs_for_project(path)
+}
+
+/// Return the latest version of a spec's markdown.
+#[tauri::command]
+pub fn get_spec(project_path: String, spec_id: String) -> Result<String, String> {
+    let path = Path::new(&project_path);
+    let meta = read_metadata(path, &spec_id)?;
+    fs::read_to_string(spec_file(path, &spec_id, meta.version)).map_err(|e| e.to_string())
+}
+
+/// Save a spec. Creates a new spec when `spec_id` is unknown, otherwise
+/// writes the content as a new version.
+#[tauri::command]
+pub fn save_spec(
+    project_path: String,
+    spec_id: Option<String>,
+    title: String,
+    content: String,
+) -> Result<SpecMetadata, String> {
+    let path = Path::new(&project_path);
+    let now = Utc::now().to_rfc3339();
+
+    // Serialize the read-modify-write on this spec's metadata.
+    let id = spec_id.clone().unwrap_or_else(|| slugify(&title));
+    let lock = spec_lock(path, &id);
+    let _guard = lock.lock().unwrap();
+
+    let mut meta = match spec_id {
+        Some(id) => {
+            let mut existing = read_metadata(path, &id)?;
+            existing.version += 1;
+            existing.title = title;
+            existing.updated_at = now;
+            existing
+        }
+        None => SpecMetadata {
+            id: slugify(&title),
+            title,
+            created_at: now.clone(),
+            updated_at: now,
+            approved: false,
+            status: SpecStatus::Draft,
+            version: 1,
+            issue_url: None,
+            issue_urls: Vec::new(),
+            issue_numbers: Vec::new(),
+        },
+    };
+
+    // Lint warnings don't block saving, but they should be visible in the
+    // app logs before the spec turns into issues.
+    let lint = lint_spec_content(&content, &required_sections(path));
+    for section in &lint.missing_sections {
+        log::warn!("Spec {} is missing section \"{}\"", meta.id, section);
+    }
+    for warning in &lint.warnings {
+        log::warn!("Spec {}: {}", meta.id, warning);
+    }
+
+    fs::create_dir_all(specs_dir(path)).map_err(|e| e.to_string())?;
+    fs::write(spec_file(path, &meta.id, meta.version), content).map_err(|e| e.to_string())?;
+    write_metadata(path, &meta)?;
+    let _ = crate::board::apply_trigger(path, &meta.id, &meta.title, crate::board::BoardTrigger::SpecCreated);
+    Ok(meta)
+}
+
+/// Mark a spec as approved for implementation.
+#[tauri::command]
+pub fn approve_spec(project_path: String, spec_id: String) -> Result<SpecMetadata, String> {
+    set_spec_status(project_path, spec_id, SpecStatus::Approved)
+}
+
+/// Move a spec to a new lifecycle status, validating the transition. The
+/// legacy `approved` flag follows the status.
+#[tauri::command]
+pub fn set_spec_status(
+    project_path: String,
+    spec_id: String,
+    status: SpecStatus,
+) -> Result<SpecMetadata, String> {
+    let path = Path::new(&project_path);
+    let lock = spec_lock(path, &spec_id);
+    let _guard = lock.lock().unwrap();
+    let mut meta = read_metadata(path, &spec_id)?;
+    if meta.status == status {
+        return Ok(meta);
+    }
+    if !meta.status.can_transition_to(status) {
+        return Err(format!(
+            "Spec {} cannot move from {:?} to {:?}",
+            spec_id, meta.status, status
+        ));
+    }
+    meta.status = status;
+    meta.approved = matches!(status, SpecStatus::Approved | SpecStatus::Implemented);
+    meta.updated_at = Utc::now().to_rfc3339();
+    write_metadata(path, &meta)?;
+    if status == SpecStatus::Approved {
+        let _ = crate::board::apply_trigger(
+            path,
+            &meta.id,
+            &meta.title,
+            crate::board::BoardTrigger::SpecApproved,
+        );
+    }
+    Ok(meta)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedIssue {
+    pub title: String,
+    pub url: String,
+}
+
+/// One issue proposed by the decomposition, before anything is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposedIssue {
+    pub title: String,
+    pub body: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// 1-based indices of issues in this list that must land first.
+    #[serde(default)]
+    pub depends_on: Vec<usize>,
+}
+
+const DECOMPOSE_SYSTEM: &str = "You split an approved software spec into \
+scoped, independently implementable GitHub issues. Return ONLY a JSON array \
+of objects with \"title\", \"body\" (markdown, with acceptance criteria), \
+\"labels\" (strings), and \"depends_on\" (1-based indices of issues in this \
+array that must be completed first). Order the array so dependencies come \
+before dependents. Aim for issues an agent can finish in one run.";
+
+/// Parse the model's decomposition, tolerating markdown fences.
+fn parse_decomposition(response: &str) -> Result<Vec<ProposedIssue>, String> {
+    let trimmed = response.trim();
+    let json = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .map(|s| s.trim_end_matches("```").trim())
+        .unwrap_or(trimmed);
+    serde_json::from_str(json).map_err(|e| format!("Unparseable decomposition: {}", e))
+}
+
+/// Split an approved spec into an ordered list of proposed GitHub issues via
+/// the architect model. Nothing is created; the list comes back for review
+/// and goes through [`create_github_issue_batch`] once accepted.
+#[tauri::command]
+pub async fn decompose_spec(
+    project_path: String,
+    spec_id: String,
+) -> Result<Vec<ProposedIssue>, String> {
+    let path = Path::new(&project_path);
+    let meta = read_metadata(path, &spec_id)?;
+    if !meta.approved {
+        return Err(format!("Spec {} is not approved", spec_id));
+    }
+    let content =
+        fs::read_to_string(spec_file(path, &spec_id, meta.version)).map_err(|e| e.to_string())?;
+
+    let response = crate::architect::complete(DECOMPOSE_SYSTEM, &content).await?;
+    let issues = parse_decomposition(&response)?;
+    if issues.is_empty() {
+        return Err("Decomposition produced no issues".to_string());
+    }
+    Ok(issues)
+}
+
+/// Create a reviewed batch of issues in order. When `spec_id` is given the
+/// created URLs are recorded in that spec's metadata.
+#[tauri::command]
+pub async fn create_github_issue_batch(
+    project_path: String,
+    issues: Vec<ProposedIssue>,
+    spec_id: Option<String>,
+) -> Result<Vec<CreatedIssue>, String> {
+    // Issues are created in order, so dependencies (which point backwards)
+    // can be resolved to real URLs as we go.
+    let mut created: Vec<CreatedIssue> = Vec::new();
+    for issue in &issues {
+        let mut body = issue.body.clone();
+        let deps: Vec<&str> = issue
+            .depends_on
+            .iter()
+            .filter_map(|&i| created.get(i.saturating_sub(1)))
+            .map(|c| c.url.as_str())
+            .collect();
+        if !deps.is_empty() {
+            body.push_str(&format!("\n\nDepends on: {}", deps.join(", ")));
+        }
+        let url = crate::commands::create_github_issue(
+            project_path.clone(),
+            issue.title.clone(),
+            body,
+            Some(issue.labels.clone()),
+        )
+        .await?;
+        created.push(CreatedIssue {
+            title: issue.title.clone(),
+            url,
+        });
+    }
+
+    if let Some(spec_id) = spec_id {
+        let path = Path::new(&project_path);
+        let lock = spec_lock(path, &spec_id);
+        let _guard = lock.lock().unwrap();
+        let mut meta = read_metadata(path, &spec_id)?;
+        meta.issue_urls
+            .extend(created.iter().map(|c| c.url.clone()));
+        meta.issue_numbers
+            .extend(created.iter().filter_map(|c| issue_number_from_url(&c.url)));
+        meta.updated_at = Utc::now().to_rfc3339();
+        write_metadata(path, &meta)?;
+    }
+
+    Ok(created)
+}
+
+/// Issue number from a GitHub issue URL (the trailing path segment).
+fn issue_number_from_url(url: &str) -> Option<u64> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+/// State of one issue linked to a spec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedIssue {
+    pub number: u64,
+    pub title: String,
+    /// "open" or "closed".
+    pub state: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecIssueStatus {
+    pub spec_id: String,
+    pub total: usize,
+    pub closed: usize,
+    pub issues: Vec<LinkedIssue>,
+}
+
+/// Query GitHub for the state of every issue linked to a spec, so the spec
+/// view can show "3/5 issues closed".
+#[tauri::command]
+pub fn sync_spec_issues(project_path: String, spec_id: String) -> Result<SpecIssueStatus, String> {
+    let path = Path::new(&project_path);
+    let meta = read_metadata(path, &spec_id)?;
+
+    // Older metadata only has URLs; derive the numbers from those.
+    let mut numbers = meta.issue_numbers.clone();
+    if numbers.is_empty() {
+        numbers = meta
+            .issue_urls
+            .iter()
+            .filter_map(|url| issue_number_from_url(url))
+            .collect();
+    }
+
+    let mut issues = Vec::new();
+    for number in numbers {
+        crate::rate_limit::acquire_blocking(crate::rate_limit::Provider::GitHub);
+        let output = std::process::Command::new("gh")
+            .args([
+                "issue",
+                "view",
+                &number.to_string(),
+                "--json",
+                "number,title,state,url",
+            ])
+            .current_dir(path)
+            .output()
+            .map_err(|e| format!("Failed to run gh: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "gh issue view {} failed: {}",
+                number,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let parsed: serde_json::Value =
+            serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())?;
+        issues.push(LinkedIssue {
+            number,
+            title: parsed
+                .get("title")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            state: parsed
+                .get("state")
+                .and_then(|s| s.as_str())
+                .unwrap_or("open")
+                .to_lowercase(),
+            url: parsed
+                .get("url")
+                .and_then(|u| u.as_str())
+                .unwrap_or_default()
+                .to_string(),
+        });
+    }
+
+    let closed = issues.iter().filter(|i| i.state == "closed").count();
+    Ok(SpecIssueStatus {
+        spec_id,
+        total: issues.len(),
+        closed,
+        issues,
+    })
+}
+
+/// One-shot pipeline: decompose an approved spec and create every issue,
+/// recording the URLs in the spec's metadata.
+#[tauri::command]
+pub async fn create_issues_from_spec(
+    project_path: String,
+    spec_id: String,
+) -> Result<Vec<CreatedIssue>, String> {
+    let issues = decompose_spec(project_path.clone(), spec_id.clone()).await?;
+    create_github_issue_batch(project_path, issues, Some(spec_id)).await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecRefinement {
+    pub spec_id: String,
+    /// The version the refinement was computed against.
+    pub base_version: u32,
+    /// The full proposed markdown.
+    pub proposed: String,
+    /// Line diff against the base version, for the approval view.
+    pub diff: String,
+}
+
+const REFINE_SYSTEM: &str = "You revise software specs. Given a spec in \
+markdown and an instruction, return ONLY the complete revised spec markdown \
+— no commentary, no fences. Keep everything the instruction doesn't touch \
+unchanged.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// One line of a structured spec diff, with positions in both versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub content: String,
+    /// 1-based line number in the old version; `None` for added lines.
+    pub old_line: Option<u32>,
+    /// 1-based line number in the new version; `None` for removed lines.
+    pub new_line: Option<u32>,
+}
+
+/// Structured line diff (LCS). Specs are small enough for the quadratic
+/// table.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut push = |kind: DiffLineKind, content: &str, i: usize, j: usize| {
+        lines.push(DiffLine {
+            kind,
+            content: content.to_string(),
+            old_line: (kind != DiffLineKind::Added).then(|| i as u32 + 1),
+            new_line: (kind != DiffLineKind::Removed).then(|| j as u32 + 1),
+        });
+    };
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            push(DiffLineKind::Context, a[i], i, j);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            push(DiffLineKind::Removed, a[i], i, j);
+            i += 1;
+        } else {
+            push(DiffLineKind::Added, b[j], i, j);
+            j += 1;
+        }
+    }
+    while i < a.len() {
+        push(DiffLineKind::Removed, a[i], i, j);
+        i += 1;
+    }
+    while j < b.len() {
+        push(DiffLineKind::Added, b[j], i, j);
+        j += 1;
+    }
+    lines
+}
+
+/// Minimal line-based diff with `-`/`+`/` ` prefixes, for showing a
+/// proposed spec revision before it's accepted.
+pub fn line_diff(old: &str, new: &str) -> String {
+    let mut out = String::new();
+    for line in diff_lines(old, new) {
+        let prefix = match line.kind {
+            DiffLineKind::Context => ' ',
+            DiffLineKind::Added => '+',
+            DiffLineKind::Removed => '-',
+        };
+        out.push_str(&format!("{} {}\n", prefix, line.content));
+    }
+    out
+}
+
+/// Diff of a spec between two stored versions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecDiff {
+    pub spec_id: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub added: usize,
+    pub removed: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Structured diff between two versions of a spec, so reviewers see what
+/// changed since the last approved version instead of eyeballing two files.
+#[tauri::command]
+pub fn diff_spec_versions(
+    project_path: String,
+    spec_id: String,
+    from_version: u32,
+    to_version: u32,
+) -> Result<SpecDiff, String> {
+    let path = Path::new(&project_path);
+    let meta = read_metadata(path, &spec_id)?;
+    for version in [from_version, to_version] {
+        if version == 0 || version > meta.version {
+            return Err(format!(
+                "Spec {} has no version {} (latest is {})",
+                spec_id, version, meta.version
+            ));
+        }
+    }
+    let from = fs::read_to_string(spec_file(path, &spec_id, from_version))
+        .map_err(|e| format!("Failed to read v{}: {}", from_version, e))?;
+    let to = fs::read_to_string(spec_file(path, &spec_id, to_version))
+        .map_err(|e| format!("Failed to read v{}: {}", to_version, e))?;
+
+    let lines = diff_lines(&from, &to);
+    let added = lines
+        .iter()
+        .filter(|l| l.kind == DiffLineKind::Added)
+        .count();
+    let removed = lines
+        .iter()
+        .filter(|l| l.kind == DiffLineKind::Removed)
+        .count();
+    Ok(SpecDiff {
+        spec_id,
+        from_version,
+        to_version,
+        added,
+        removed,
+        lines,
+    })
+}
+
+/// Ask the architect to revise a spec per the instructions. Nothing is saved;
+/// the proposed version and its diff come back for approval, and
+/// [`apply_spec_refinement`] persists an accepted proposal.
+#[tauri::command]
+pub async fn refine_spec(
+    project_path: String,
+    spec_id: String,
+    instructions: String,
+) -> Result<SpecRefinement, String> {
+    let path = Path::new(&project_path);
+    let meta = read_metadata(path, &spec_id)?;
+    let current =
+        fs::read_to_string(spec_file(path, &spec_id, meta.version)).map_err(|e| e.to_string())?;
+
+    let user = format!("# Spec\n\n{}\n\n# Instruction\n\n{}", current, instructions);
+    let response = crate::architect::complete(REFINE_SYSTEM, &user).await?;
+    let proposed = response
+        .trim()
+        .strip_prefix("```markdown")
+        .or_else(|| response.trim().strip_prefix("```"))
+        .map(|s| s.trim_end_matches("```").trim().to_string())
+        .unwrap_or_else(|| response.trim().to_string());
+
+    let diff = line_diff(&current, &proposed);
+    Ok(SpecRefinement {
+        spec_id,
+        base_version: meta.version,
+        proposed,
+        diff,
+    })
+}
+
+/// Save an accepted refinement as a new spec version.
+#[tauri::command]
+pub fn apply_spec_refinement(
+    project_path: String,
+    spec_id: String,
+    content: String,
+) -> Result<SpecMetadata, String> {
+    let meta = read_metadata(Path::new(&project_path), &spec_id)?;
+    save_spec(project_path, Some(spec_id), meta.title, content)
+}
+
+/// Delete a spec's versions and metadata.
+#[tauri::command]
+pub fn delete_spec(project_path: String, spec_id: String) -> Result<(), String> {
+    let path = Path::new(&project_path);
+    let lock = spec_lock(path, &spec_id);
+    let _guard = lock.lock().unwrap();
+    let meta = read_metadata(path, &spec_id)?;
+    for version in 1..=meta.version {
+        let _ = fs::remove_file(spec_file(path, &spec_id, version));
+    }
+    fs::remove_file(metadata_path(path, &spec_id)).map_err(|e| e.to_string())
+}
+
+/// Sections every spec should have, in order. Projects override the list
+/// with the `## ` headings of their own `.sentra/spec-template.md`.
+const DEFAULT_REQUIRED_SECTIONS: [&str; 3] =
+    ["Problem Statement", "Acceptance Criteria", "Out of Scope"];
+
+const DEFAULT_SPEC_TEMPLATE: &str = "# <title>\n\n## Problem Statement\n\n\
+<what's wrong today and for whom>\n\n## Acceptance Criteria\n\n- [ ] ...\n\n\
+## Out of Scope\n\n- ...\n";
+
+fn spec_template_path(project_path: &Path) -> PathBuf {
+    project_path.join(".sentra").join("spec-template.md")
+}
+
+/// The spec template for a project: its `.sentra/spec-template.md` when
+/// present, otherwise the built-in one.
+#[tauri::command]
+pub fn get_spec_template(project_path: String) -> Result<String, String> {
+    Ok(
+        fs::read_to_string(spec_template_path(Path::new(&project_path)))
+            .unwrap_or_else(|_| DEFAULT_SPEC_TEMPLATE.to_string()),
+    )
+}
+
+/// Required section names for a project, from its template's `## ` headings.
+fn required_sections(project_path: &Path) -> Vec<String> {
+    let from_template = fs::read_to_string(spec_template_path(project_path))
+        .map(|template| {
+            template
+                .lines()
+                .filter_map(|line| line.strip_prefix("## "))
+                .map(|heading| heading.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if from_template.is_empty() {
+        DEFAULT_REQUIRED_SECTIONS
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    } else {
+        from_template
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecLint {
+    /// Required sections the spec is missing.
+    pub missing_sections: Vec<String>,
+    /// Formatting problems, each prefixed with its 1-based line number.
+    pub warnings: Vec<String>,
+}
+
+impl SpecLint {
+    pub fn is_clean(&self) -> bool {
+        self.missing_sections.is_empty() && self.warnings.is_empty()
+    }
+}
+
+/// Check a spec against the required sections and basic markdown hygiene.
+pub fn lint_spec_content(content: &str, required: &[String]) -> SpecLint {
+    let mut warnings = Vec::new();
+    let mut headings: Vec<String> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if hashes == 0 {
+            continue;
+        }
+        let rest = &line[hashes..];
+        if !rest.starts_with(' ') {
+            warnings.push(format!(
+                "line {}: malformed heading {:?} (missing space after #)",
+                index + 1,
+                line
+            ));
+            continue;
+        }
+        let heading = rest.trim();
+        if heading.is_empty() {
+            warnings.push(format!("line {}: empty heading", index + 1));
+            continue;
+        }
+        headings.push(heading.to_lowercase());
+    }
+
+    let missing_sections = required
+        .iter()
+        .filter(|section| !headings.iter().any(|h| h == &section.to_lowercase()))
+        .cloned()
+        .collect();
+
+    SpecLint {
+        missing_sections,
+        warnings,
+    }
+}
+
+/// Lint a spec draft against the project's template.
+#[tauri::command]
+pub fn lint_spec(project_path: String, content: String) -> Result<SpecLint, String> {
+    Ok(lint_spec_content(
+        &content,
+        &required_sections(Path::new(&project_path)),
+    ))
+}
+
+/// Derive a filesystem-safe id from a spec title.
+pub fn slugify(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug.trim_matches('-').to_string();
+    let mut result = String::new();
+    let mut last_dash = false;
+    for c in slug.chars() {
+        if c == '-' {
+            if !last_dash {
+                result.push(c);
+            }
+            last_dash = true;
+        } else {
+            result.push(c);
+            last_dash = false;
+        }
+    }
+    result
+}
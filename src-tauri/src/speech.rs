@@ -0,0 +1,188 @@
+//! Speech queue.
+//!
+//! Serializes TTS playback so concurrent notifications don't talk over each
+//! other. Announcements are queued with a priority and played one at a time
+//! by a single worker task; the queue is bounded so a burst of events can't
+//! back speech up for minutes.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Notify;
+
+use crate::settings;
+
+/// Beyond this, lowest-priority entries are dropped first.
+const MAX_QUEUE_LEN: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeechPriority {
+    Low,
+    Normal,
+    Critical,
+}
+
+#[derive(Debug, Clone)]
+struct QueuedSpeech {
+    message: String,
+    priority: SpeechPriority,
+}
+
+static QUEUE: Mutex<VecDeque<QueuedSpeech>> = Mutex::new(VecDeque::new());
+static WAKE: OnceLock<Notify> = OnceLock::new();
+static WORKER_STARTED: OnceLock<()> = OnceLock::new();
+static APP: OnceLock<AppHandle> = OnceLock::new();
+
+/// Store the app handle so the worker can emit speech lifecycle events.
+/// Called once from setup.
+pub fn init(app: AppHandle) {
+    let _ = APP.set(app);
+}
+
+fn wake() -> &'static Notify {
+    WAKE.get_or_init(Notify::new)
+}
+
+/// Queue a message for speech. Returns the queue position (0 = next).
+pub fn enqueue(message: String, priority: SpeechPriority) -> usize {
+    ensure_worker();
+    let mut queue = QUEUE.lock().unwrap();
+
+    if queue.len() >= MAX_QUEUE_LEN {
+        // Drop the lowest-priority entry to make room; refuse the new entry
+        // instead when it's the lowest itself.
+        let lowest = queue
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, q)| q.priority)
+            .map(|(i, q)| (i, q.priority));
+        match lowest {
+            Some((index, lowest_priority)) if lowest_priority <= priority => {
+                queue.remove(index);
+            }
+            _ => return queue.len(),
+        }
+    }
+
+    // Insert before the first lower-priority entry so critical alerts jump
+    // the line but equal priorities stay FIFO.
+    let position = queue
+        .iter()
+        .position(|q| q.priority < priority)
+        .unwrap_or(queue.len());
+    queue.insert(position, QueuedSpeech { message, priority });
+    drop(queue);
+    wake().notify_one();
+    position
+}
+
+fn ensure_worker() {
+    WORKER_STARTED.get_or_init(|| {
+        tauri::async_runtime::spawn(worker());
+    });
+}
+
+async fn worker() {
+    loop {
+        let next = QUEUE.lock().unwrap().pop_front();
+        let Some(item) = next else {
+            wake().notified().await;
+            continue;
+        };
+        emit_speech_event("speech-started", &item.message);
+        if let Err(e) = speak_now(&item.message).await {
+            log::error!("Speech playback failed: {}", e);
+        }
+        emit_speech_event("speech-finished", &item.message);
+    }
+}
+
+fn emit_speech_event(event: &str, message: &str) {
+    if let Some(app) = APP.get() {
+        let _ = app.emit(event, message.to_string());
+    }
+}
+
+/// Cancel the announcement currently playing. Queued announcements are kept;
+/// pass `clear_queue` to drop those too.
+#[tauri::command]
+pub fn stop_speaking(clear_queue: Option<bool>) -> Result<bool, String> {
+    if clear_queue.unwrap_or(false) {
+        QUEUE.lock().unwrap().clear();
+    }
+    Ok(settings::stop_current_playback())
+}
+
+/// Synthesize and play a single message, blocking the worker (not the
+/// runtime) until playback finishes. Falls back to the OS speech engine when
+/// OpenAI TTS is unavailable (missing key, network down) so critical alerts
+/// are never silent.
+async fn speak_now(message: &str) -> Result<(), String> {
+    let loaded = settings::load_settings()?;
+    match speak_via_provider(&loaded, message).await {
+        Ok(()) => Ok(()),
+        Err(e) if loaded.system_tts_fallback => {
+            log::warn!("TTS provider unavailable ({}); using system TTS", e);
+            let message = message.to_string();
+            tokio::task::spawn_blocking(move || speak_with_system_tts(&message))
+                .await
+                .map_err(|e| e.to_string())?
+        }
+        Err(e) => Err(e),
+    }
+}
+
+async fn speak_via_provider(loaded: &settings::Settings, message: &str) -> Result<(), String> {
+    let provider = crate::tts::provider_from_settings(loaded);
+    // Cache keys are provider-qualified so switching providers doesn't
+    // replay the other engine's audio.
+    let cache_voice = format!("{}-{}", provider.name(), provider.voice());
+    let audio = match settings::read_cached_audio(&cache_voice, message, loaded.speech_rate) {
+        Some(cached) => cached,
+        None => {
+            let fetched = provider.synthesize(message, loaded.speech_rate).await?;
+            let _ =
+                settings::write_cached_audio(&cache_voice, message, loaded.speech_rate, &fetched);
+            fetched
+        }
+    };
+    let volume = loaded.speech_volume;
+    let device = loaded.audio_output_device.clone();
+    tokio::task::spawn_blocking(move || {
+        settings::play_audio_cross_platform(audio, volume, device.as_deref())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Speak through the platform's native engine: `say` on macOS, SAPI via
+/// PowerShell on Windows, speech-dispatcher on Linux.
+pub fn speak_with_system_tts(message: &str) -> Result<(), String> {
+    let status = if cfg!(target_os = "macos") {
+        std::process::Command::new("say").arg(message).status()
+    } else if cfg!(target_os = "windows") {
+        std::process::Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-Command",
+                &format!(
+                    "Add-Type -AssemblyName System.Speech; \
+                     (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+                    message.replace('\'', "''")
+                ),
+            ])
+            .status()
+    } else {
+        std::process::Command::new("spd-say")
+            .args(["--wait", message])
+            .status()
+    };
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("System TTS exited with {}", status)),
+        Err(e) => Err(format!("System TTS unavailable: {}", e)),
+    }
+}
@@ -0,0 +1,172 @@
+//! Disk usage reporting for Sentra-managed data.
+//!
+//! Sizes everything Sentra writes under `~/.claude` (telemetry logs, audio
+//! cache, agent workdirs, template cache, ledgers) plus per-project spec
+//! archives, and offers per-category cleanup so the directory doesn't grow
+//! unbounded.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::commands;
+use crate::settings;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageCategory {
+    /// Stable id accepted by [`clean_storage_category`].
+    pub id: String,
+    pub label: String,
+    /// Directory backing the category; `None` when it aggregates several
+    /// locations (spec archives span every tracked project).
+    pub path: Option<String>,
+    pub bytes: u64,
+    pub files: u64,
+    /// Whether the category holds regenerable data that cleanup may delete.
+    pub cleanable: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageReport {
+    pub total_bytes: u64,
+    pub categories: Vec<StorageCategory>,
+}
+
+/// Recursive (bytes, file count) of a directory. Missing paths size to zero.
+fn dir_size(path: &Path) -> (u64, u64) {
+    let Ok(entries) = fs::read_dir(path) else {
+        return (0, 0);
+    };
+    let mut bytes = 0;
+    let mut files = 0;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let Ok(meta) = entry.metadata() else { continue };
+        if meta.is_dir() {
+            let (b, f) = dir_size(&entry.path());
+            bytes += b;
+            files += f;
+        } else {
+            bytes += meta.len();
+            files += 1;
+        }
+    }
+    (bytes, files)
+}
+
+fn category(id: &str, label: &str, path: PathBuf, cleanable: bool) -> StorageCategory {
+    let (bytes, files) = dir_size(&path);
+    StorageCategory {
+        id: id.to_string(),
+        label: label.to_string(),
+        path: Some(path.to_string_lossy().to_string()),
+        bytes,
+        files,
+        cleanable,
+    }
+}
+
+/// Ledger and state files living directly in `~/.claude/sentra`.
+fn ledger_sizes() -> Result<(u64, u64), String> {
+    let dir = settings::sentra_dir()?;
+    let mut bytes = 0;
+    let mut files = 0;
+    for name in [
+        "time-ledger.jsonl",
+        "usage-ledger.jsonl",
+        "learning-queue.json",
+        "session.json",
+    ] {
+        if let Ok(meta) = fs::metadata(dir.join(name)) {
+            bytes += meta.len();
+            files += 1;
+        }
+    }
+    Ok((bytes, files))
+}
+
+/// Size Sentra's on-disk footprint by category.
+#[tauri::command]
+pub fn get_storage_report() -> Result<StorageReport, String> {
+    let mut categories = vec![
+        category(
+            "telemetry",
+            "Agent telemetry logs",
+            commands::telemetry_dir()?,
+            true,
+        ),
+        category(
+            "audio_cache",
+            "TTS audio cache",
+            settings::sentra_dir()?.join("audio-cache"),
+            true,
+        ),
+        category(
+            "agent_workdirs",
+            "Local agent workdirs",
+            settings::sentra_dir()?.join("agents"),
+            true,
+        ),
+        category(
+            "template_cache",
+            "Remote template cache",
+            settings::sentra_dir()?.join("templates").join("cache"),
+            true,
+        ),
+    ];
+
+    let (ledger_bytes, ledger_files) = ledger_sizes()?;
+    categories.push(StorageCategory {
+        id: "ledgers".to_string(),
+        label: "Ledgers and session state".to_string(),
+        path: Some(settings::sentra_dir()?.to_string_lossy().to_string()),
+        bytes: ledger_bytes,
+        files: ledger_files,
+        cleanable: false,
+    });
+
+    // Spec archives live inside each tracked project, so they aggregate
+    // rather than pointing at one directory.
+    let mut spec_bytes = 0;
+    let mut spec_files = 0;
+    for project in commands::read_tracked_projects()? {
+        let (b, f) = dir_size(&project.join(".sentra").join("specs"));
+        spec_bytes += b;
+        spec_files += f;
+    }
+    categories.push(StorageCategory {
+        id: "spec_archives".to_string(),
+        label: "Project spec archives".to_string(),
+        path: None,
+        bytes: spec_bytes,
+        files: spec_files,
+        cleanable: false,
+    });
+
+    let total_bytes = categories.iter().map(|c| c.bytes).sum();
+    Ok(StorageReport {
+        total_bytes,
+        categories,
+    })
+}
+
+/// Delete the contents of a cleanable category and return the bytes freed.
+/// Ledgers and spec archives are project data and cannot be cleaned here.
+#[tauri::command]
+pub fn clean_storage_category(category_id: String) -> Result<u64, String> {
+    let dir = match category_id.as_str() {
+        "telemetry" => commands::telemetry_dir()?,
+        "audio_cache" => settings::sentra_dir()?.join("audio-cache"),
+        "agent_workdirs" => settings::sentra_dir()?.join("agents"),
+        "template_cache" => settings::sentra_dir()?.join("templates").join("cache"),
+        other => return Err(format!("Category is not cleanable: {}", other)),
+    };
+    let (bytes, _) = dir_size(&dir);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).map_err(|e| e.to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,522 @@
+//! Project templates.
+//!
+//! Each template is a set of starter files laid down by `create_project`.
+//! Templates ship a CLAUDE.md so agents dispatched into the project know its
+//! conventions from the first run.
+
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::settings;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateFile {
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+    pub files: Vec<TemplateFile>,
+    /// Shell commands run in the project directory after the files are laid
+    /// down (dependency installs, generators).
+    #[serde(default)]
+    pub post_create_commands: Vec<String>,
+}
+
+fn file(path: &str, content: &str) -> TemplateFile {
+    TemplateFile {
+        path: path.to_string(),
+        content: content.to_string(),
+    }
+}
+
+pub fn create_nextjs_template() -> Template {
+    Template {
+        name: "nextjs".to_string(),
+        display_name: "Next.js 15".to_string(),
+        description: "Next.js 15 App Router with TypeScript strict mode".to_string(),
+        files: vec![
+            file(
+                "package.json",
+                r#"{
+  "name": "new-project",
+  "version": "0.1.0",
+  "private": true,
+  "scripts": {
+    "dev": "next dev",
+    "build": "next build",
+    "start": "next start",
+    "lint": "next lint",
+    "type-check": "tsc --noEmit"
+  },
+  "dependencies": {
+    "next": "^15.0.0",
+    "react": "^19.0.0",
+    "react-dom": "^19.0.0"
+  },
+  "devDependencies": {
+    "@types/node": "^22.0.0",
+    "@types/react": "^19.0.0",
+    "typescript": "^5.6.0"
+  }
+}
+"#,
+            ),
+            file(
+                "tsconfig.json",
+                r#"{
+  "compilerOptions": {
+    "strict": true,
+    "target": "ES2022",
+    "module": "esnext",
+    "moduleResolution": "bundler",
+    "jsx": "preserve",
+    "esModuleInterop": true,
+    "skipLibCheck": true
+  },
+  "include": ["src"]
+}
+"#,
+            ),
+            file(
+                "src/app/page.tsx",
+                "export default function Home() {\n  return <main>Hello</main>\n}\n",
+            ),
+            file(
+                "CLAUDE.md",
+                "# Project Context\n\n- Next.js 15 App Router, TypeScript strict mode.\n- Tests first (TDD). Run `npm run type-check` and `npm run lint` before committing.\n- No `any`, no `@ts-ignore`.\n",
+            ),
+            file(".gitignore", "node_modules/\n.next/\nout/\n.env\n"),
+        ],
+        post_create_commands: vec!["npm install".to_string()],
+    }
+}
+
+pub fn create_python_template() -> Template {
+    Template {
+        name: "python".to_string(),
+        display_name: "Python".to_string(),
+        description: "Python project with pytest and ruff".to_string(),
+        files: vec![
+            file(
+                "pyproject.toml",
+                r#"[project]
+name = "new-project"
+version = "0.1.0"
+requires-python = ">=3.11"
+
+[tool.pytest.ini_options]
+testpaths = ["tests"]
+
+[tool.ruff]
+line-length = 100
+"#,
+            ),
+            file("src/__init__.py", ""),
+            file(
+                "tests/test_smoke.py",
+                "def test_smoke():\n    assert True\n",
+            ),
+            file(
+                "CLAUDE.md",
+                "# Project Context\n\n- Python 3.11+, pytest for tests, ruff for linting.\n- Write tests first. Run `pytest` and `ruff check .` before committing.\n",
+            ),
+            file(".gitignore", "__pycache__/\n.venv/\n*.egg-info/\n.env\n"),
+        ],
+        post_create_commands: Vec::new(),
+    }
+}
+
+pub fn create_node_template() -> Template {
+    Template {
+        name: "node".to_string(),
+        display_name: "Node.js".to_string(),
+        description: "Node.js service with TypeScript and vitest".to_string(),
+        files: vec![
+            file(
+                "package.json",
+                r#"{
+  "name": "new-project",
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {
+    "build": "tsc",
+    "test": "vitest run",
+    "lint": "eslint src"
+  },
+  "devDependencies": {
+    "typescript": "^5.6.0",
+    "vitest": "^2.0.0"
+  }
+}
+"#,
+            ),
+            file(
+                "tsconfig.json",
+                r#"{
+  "compilerOptions": {
+    "strict": true,
+    "target": "ES2022",
+    "module": "nodenext",
+    "outDir": "dist"
+  },
+  "include": ["src"]
+}
+"#,
+            ),
+            file("src/index.ts", "export {}\n"),
+            file(
+                "CLAUDE.md",
+                "# Project Context\n\n- Node.js + TypeScript strict mode, vitest for tests.\n- Write tests first. Run `npm test` and `npm run lint` before committing.\n",
+            ),
+            file(".gitignore", "node_modules/\ndist/\n.env\n"),
+        ],
+        post_create_commands: vec!["npm install".to_string()],
+    }
+}
+
+pub fn create_rust_template() -> Template {
+    Template {
+        name: "rust".to_string(),
+        display_name: "Rust".to_string(),
+        description: "Rust binary crate with clippy config and integration tests".to_string(),
+        files: vec![
+            file(
+                "Cargo.toml",
+                r#"[package]
+name = "new-project"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+
+[lints.clippy]
+all = "deny"
+"#,
+            ),
+            file(
+                "src/main.rs",
+                "fn main() {\n    println!(\"Hello\");\n}\n",
+            ),
+            file(
+                "src/lib.rs",
+                "pub fn add(a: i64, b: i64) -> i64 {\n    a + b\n}\n",
+            ),
+            file(
+                "tests/smoke_test.rs",
+                "#[test]\nfn smoke() {\n    assert_eq!(new_project::add(2, 2), 4);\n}\n",
+            ),
+            file(
+                "CLAUDE.md",
+                "# Project Context\n\n- Rust 2021 edition. Clippy warnings are errors.\n- Write tests first. Run `cargo build`, `cargo clippy --all-targets -- -D warnings`, and `cargo test` before committing.\n- Integration tests live in `tests/`; avoid `unwrap()` outside tests.\n",
+            ),
+            file(".gitignore", "/target/\nCargo.lock\n.env\n"),
+        ],
+        post_create_commands: Vec::new(),
+    }
+}
+
+pub fn create_go_template() -> Template {
+    Template {
+        name: "go".to_string(),
+        display_name: "Go".to_string(),
+        description: "Go module with cmd/ layout and table-driven tests".to_string(),
+        files: vec![
+            file(
+                "go.mod",
+                "module new-project\n\ngo 1.23\n",
+            ),
+            file(
+                "cmd/new-project/main.go",
+                "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"Hello\")\n}\n",
+            ),
+            file(
+                "internal/app/app.go",
+                "package app\n\n// Add returns the sum of a and b.\nfunc Add(a, b int) int {\n\treturn a + b\n}\n",
+            ),
+            file(
+                "internal/app/app_test.go",
+                "package app\n\nimport \"testing\"\n\nfunc TestAdd(t *testing.T) {\n\tcases := []struct {\n\t\tname string\n\t\ta, b, want int\n\t}{\n\t\t{\"zero\", 0, 0, 0},\n\t\t{\"positive\", 2, 2, 4},\n\t\t{\"negative\", -1, 1, 0},\n\t}\n\tfor _, tc := range cases {\n\t\tt.Run(tc.name, func(t *testing.T) {\n\t\t\tif got := Add(tc.a, tc.b); got != tc.want {\n\t\t\t\tt.Errorf(\"Add(%d, %d) = %d, want %d\", tc.a, tc.b, got, tc.want)\n\t\t\t}\n\t\t})\n\t}\n}\n",
+            ),
+            file(
+                "CLAUDE.md",
+                "# Project Context\n\n- Go 1.23 module. Binaries in `cmd/`, packages in `internal/`.\n- Write table-driven tests first. Run `go vet ./...` and `go test ./...` before committing.\n- Run `gofmt` on every file you touch.\n",
+            ),
+            file(".gitignore", "/bin/\n.env\n"),
+        ],
+        post_create_commands: vec!["go mod tidy".to_string()],
+    }
+}
+
+/// All built-in templates.
+pub fn builtin_templates() -> Vec<Template> {
+    vec![
+        create_nextjs_template(),
+        create_python_template(),
+        create_node_template(),
+        create_rust_template(),
+        create_go_template(),
+    ]
+}
+
+/// Where fetched remote templates live, one clone per registry URL.
+fn template_cache_dir() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("templates").join("cache"))
+}
+
+/// Manifest a remote template repo must carry at its root as `template.json`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TemplateManifest {
+    name: String,
+    display_name: String,
+    description: String,
+    #[serde(default)]
+    post_create_commands: Vec<String>,
+}
+
+/// Derive a stable cache directory name from a registry URL.
+fn cache_slug(url: &str) -> String {
+    url.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+/// Collect every file in a template checkout (except the manifest and `.git`)
+/// as template files with repo-relative paths.
+fn collect_template_files(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<TemplateFile>,
+) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == ".git" {
+            continue;
+        }
+        if path.is_dir() {
+            collect_template_files(root, &path, files)?;
+            continue;
+        }
+        let relative = path
+            .strip_prefix(root)
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .to_string();
+        if relative == "template.json" {
+            continue;
+        }
+        // Binary starter files are rare; skip anything that isn't UTF-8.
+        if let Ok(content) = fs::read_to_string(&path) {
+            files.push(TemplateFile {
+                path: relative,
+                content,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Load a cached template checkout into a [`Template`].
+fn load_cached_template(dir: &Path) -> Result<Template, String> {
+    let manifest_path = dir.join("template.json");
+    let manifest: TemplateManifest = serde_json::from_str(
+        &fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Template has no template.json: {}", e))?,
+    )
+    .map_err(|e| format!("Invalid template.json: {}", e))?;
+    let mut files = Vec::new();
+    collect_template_files(dir, dir, &mut files)?;
+    Ok(Template {
+        name: manifest.name,
+        display_name: manifest.display_name,
+        description: manifest.description,
+        files,
+        post_create_commands: manifest.post_create_commands,
+    })
+}
+
+/// Every cached remote template that still parses.
+fn cached_templates() -> Vec<Template> {
+    let Ok(cache) = template_cache_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&cache) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| load_cached_template(&e.path()).ok())
+        .collect()
+}
+
+/// Built-in templates plus cached remote ones. Remote templates with a name
+/// colliding with a built-in shadow it, so teams can override the defaults.
+pub fn all_templates() -> Vec<Template> {
+    let mut templates = cached_templates();
+    for builtin in builtin_templates() {
+        if !templates.iter().any(|t| t.name == builtin.name) {
+            templates.push(builtin);
+        }
+    }
+    templates
+}
+
+/// Fetch (or refresh) a template repo into the local cache and return the
+/// parsed template. The repo must carry a `template.json` manifest at its
+/// root; every other file becomes a starter file.
+#[tauri::command]
+pub fn fetch_remote_template(url: String) -> Result<Template, String> {
+    let cache = template_cache_dir()?;
+    fs::create_dir_all(&cache).map_err(|e| e.to_string())?;
+    let checkout = cache.join(cache_slug(&url));
+
+    let output = if checkout.join(".git").exists() {
+        Command::new("git")
+            .args(["pull", "--ff-only"])
+            .current_dir(&checkout)
+            .output()
+    } else {
+        Command::new("git")
+            .args(["clone", "--depth", "1", &url])
+            .arg(&checkout)
+            .output()
+    }
+    .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    load_cached_template(&checkout)
+}
+
+/// Look up a template by name across built-ins and the remote cache.
+pub fn find_template(template_name: &str) -> Result<Template, String> {
+    all_templates()
+        .into_iter()
+        .find(|t| t.name == template_name)
+        .ok_or_else(|| format!("Unknown template: {}", template_name))
+}
+
+/// Write a template's files into a project directory.
+pub fn apply_template(project_path: &Path, template_name: &str) -> Result<(), String> {
+    let template = find_template(template_name)?;
+    for tf in &template.files {
+        let dest = project_path.join(&tf.path);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&dest, &tf.content).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Payload for `template-command-output` / `template-command-finished`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommandEvent {
+    project: String,
+    command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    success: Option<bool>,
+}
+
+/// Run a template's post-create commands in the project directory on a
+/// background thread, streaming each output line to the frontend as
+/// `template-command-output` events and a `template-command-finished` event
+/// per command. Failures stop the chain but never fail project creation.
+pub fn run_post_create_commands(app: AppHandle, project_path: PathBuf, commands: Vec<String>) {
+    if commands.is_empty() {
+        return;
+    }
+    let project = project_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    std::thread::spawn(move || {
+        for command in commands {
+            let mut cmd = if cfg!(target_os = "windows") {
+                let mut c = Command::new("cmd");
+                c.args(["/C", &command]);
+                c
+            } else {
+                let mut c = Command::new("sh");
+                c.args(["-c", &command]);
+                c
+            };
+            let child = cmd
+                .current_dir(&project_path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn();
+            let mut child = match child {
+                Ok(child) => child,
+                Err(e) => {
+                    let _ = app.emit(
+                        "template-command-finished",
+                        CommandEvent {
+                            project: project.clone(),
+                            command: command.clone(),
+                            line: Some(e.to_string()),
+                            success: Some(false),
+                        },
+                    );
+                    return;
+                }
+            };
+
+            if let Some(stdout) = child.stdout.take() {
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    let _ = app.emit(
+                        "template-command-output",
+                        CommandEvent {
+                            project: project.clone(),
+                            command: command.clone(),
+                            line: Some(line),
+                            success: None,
+                        },
+                    );
+                }
+            }
+
+            let success = child.wait().map(|s| s.success()).unwrap_or(false);
+            let _ = app.emit(
+                "template-command-finished",
+                CommandEvent {
+                    project: project.clone(),
+                    command: command.clone(),
+                    line: None,
+                    success: Some(success),
+                },
+            );
+            if !success {
+                break;
+            }
+        }
+    });
+}
+
+/// List available templates for the new-project dialog.
+#[tauri::command]
+pub fn get_templates_command() -> Result<Vec<Template>, String> {
+    Ok(all_templates())
+}
@@ -0,0 +1,157 @@
+//! Time tracking per project and agent.
+//!
+//! An append-only JSONL ledger at `~/.claude/sentra/time-ledger.jsonl`
+//! recording how long agents and architect sessions spend per project, for
+//! invoicing client work. Entries are deduplicated by reference id so
+//! repeated observation of the same workflow run records it only once.
+
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeEntryKind {
+    Agent,
+    Architect,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntry {
+    pub project: String,
+    pub kind: TimeEntryKind,
+    /// Unique id for dedup: workflow run id for agents, session id for
+    /// architect conversations.
+    pub reference: String,
+    pub seconds: i64,
+    pub recorded_at: String,
+}
+
+fn ledger_path() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("time-ledger.jsonl"))
+}
+
+fn read_ledger() -> Result<Vec<TimeEntry>, String> {
+    let path = ledger_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Append an entry unless its reference was already recorded.
+pub fn record(
+    project: &str,
+    kind: TimeEntryKind,
+    reference: &str,
+    seconds: i64,
+) -> Result<(), String> {
+    if seconds <= 0 {
+        return Ok(());
+    }
+    let existing: HashSet<String> = read_ledger()?.into_iter().map(|e| e.reference).collect();
+    if existing.contains(reference) {
+        return Ok(());
+    }
+    let entry = TimeEntry {
+        project: project.to_string(),
+        kind,
+        reference: reference.to_string(),
+        seconds,
+        recorded_at: Utc::now().to_rfc3339(),
+    };
+    let path = ledger_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| e.to_string())?;
+    let json = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+    writeln!(file, "{}", json).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTime {
+    pub project: String,
+    pub agent_seconds: i64,
+    pub architect_seconds: i64,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeReport {
+    pub range: String,
+    pub projects: Vec<ProjectTime>,
+    pub total_seconds: i64,
+}
+
+/// Parse a report range ("today", "week", "month") into its start time.
+pub fn range_start(range: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    match range {
+        "today" => Ok(now - Duration::days(1)),
+        "week" => Ok(now - Duration::weeks(1)),
+        "month" => Ok(now - Duration::days(30)),
+        other => Err(format!(
+            "Unknown range \"{}\" (expected today, week, or month)",
+            other
+        )),
+    }
+}
+
+/// Aggregate tracked time per project over a range.
+#[tauri::command]
+pub fn get_time_report(range: String) -> Result<TimeReport, String> {
+    let start = range_start(&range, Utc::now())?;
+    let mut projects: Vec<ProjectTime> = Vec::new();
+    let mut total = 0;
+
+    for entry in read_ledger()? {
+        let Ok(recorded) = DateTime::parse_from_rfc3339(&entry.recorded_at) else {
+            continue;
+        };
+        if recorded.with_timezone(&Utc) < start {
+            continue;
+        }
+        let slot = match projects.iter_mut().find(|p| p.project == entry.project) {
+            Some(slot) => slot,
+            None => {
+                projects.push(ProjectTime {
+                    project: entry.project.clone(),
+                    agent_seconds: 0,
+                    architect_seconds: 0,
+                    total_seconds: 0,
+                });
+                projects.last_mut().unwrap()
+            }
+        };
+        match entry.kind {
+            TimeEntryKind::Agent => slot.agent_seconds += entry.seconds,
+            TimeEntryKind::Architect => slot.architect_seconds += entry.seconds,
+        }
+        slot.total_seconds += entry.seconds;
+        total += entry.seconds;
+    }
+
+    projects.sort_by(|a, b| b.total_seconds.cmp(&a.total_seconds));
+    Ok(TimeReport {
+        range,
+        projects,
+        total_seconds: total,
+    })
+}
@@ -0,0 +1,166 @@
+//! TTS providers.
+//!
+//! Speech synthesis sits behind a provider trait so users can choose between
+//! OpenAI TTS and ElevenLabs (higher quality / custom cloned voices), each
+//! with its own API key and voice list.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{self, Settings};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceInfo {
+    pub id: String,
+    pub name: String,
+}
+
+#[async_trait]
+pub trait TtsProvider: Send + Sync {
+    /// Provider key as used in settings ("openai", "elevenlabs").
+    fn name(&self) -> &'static str;
+
+    /// The configured voice, used in audio cache keys.
+    fn voice(&self) -> &str;
+
+    /// Synthesize text to an audio buffer rodio can decode.
+    async fn synthesize(&self, text: &str, speed: f32) -> Result<Vec<u8>, String>;
+
+    /// Voices available to this account.
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, String>;
+}
+
+pub struct OpenAiTts {
+    api_key: String,
+    voice: String,
+}
+
+#[async_trait]
+impl TtsProvider for OpenAiTts {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    fn voice(&self) -> &str {
+        &self.voice
+    }
+
+    async fn synthesize(&self, text: &str, speed: f32) -> Result<Vec<u8>, String> {
+        if self.api_key.is_empty() {
+            return Err("OpenAI API key not configured".to_string());
+        }
+        settings::fetch_tts_audio(&self.api_key, &self.voice, text, speed).await
+    }
+
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, String> {
+        // The TTS API has a fixed, documented voice set.
+        Ok(["alloy", "echo", "fable", "onyx", "nova", "shimmer"]
+            .iter()
+            .map(|v| VoiceInfo {
+                id: (*v).to_string(),
+                name: (*v).to_string(),
+            })
+            .collect())
+    }
+}
+
+pub struct ElevenLabsTts {
+    api_key: String,
+    voice_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ElevenLabsVoices {
+    voices: Vec<ElevenLabsVoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ElevenLabsVoice {
+    voice_id: String,
+    name: String,
+}
+
+#[async_trait]
+impl TtsProvider for ElevenLabsTts {
+    fn name(&self) -> &'static str {
+        "elevenlabs"
+    }
+
+    fn voice(&self) -> &str {
+        &self.voice_id
+    }
+
+    async fn synthesize(&self, text: &str, _speed: f32) -> Result<Vec<u8>, String> {
+        if self.api_key.is_empty() {
+            return Err("ElevenLabs API key not configured".to_string());
+        }
+        let url = format!(
+            "https://api.elevenlabs.io/v1/text-to-speech/{}",
+            self.voice_id
+        );
+        let client = reqwest::Client::new();
+        let response = client
+            .post(&url)
+            .header("xi-api-key", &self.api_key)
+            .json(&serde_json::json!({
+                "text": text,
+                "model_id": "eleven_multilingual_v2",
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("ElevenLabs request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("ElevenLabs API error: {}", response.status()));
+        }
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, String> {
+        if self.api_key.is_empty() {
+            return Err("ElevenLabs API key not configured".to_string());
+        }
+        let client = reqwest::Client::new();
+        let response = client
+            .get("https://api.elevenlabs.io/v1/voices")
+            .header("xi-api-key", &self.api_key)
+            .send()
+            .await
+            .map_err(|e| format!("ElevenLabs request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("ElevenLabs API error: {}", response.status()));
+        }
+        let parsed: ElevenLabsVoices = response.json().await.map_err(|e| e.to_string())?;
+        Ok(parsed
+            .voices
+            .into_iter()
+            .map(|v| VoiceInfo {
+                id: v.voice_id,
+                name: v.name,
+            })
+            .collect())
+    }
+}
+
+/// Build the provider selected in settings. Unknown values fall back to
+/// OpenAI rather than silencing notifications.
+pub fn provider_from_settings(loaded: &Settings) -> Box<dyn TtsProvider> {
+    match loaded.tts_provider.as_str() {
+        "elevenlabs" => Box::new(ElevenLabsTts {
+            api_key: loaded.elevenlabs_api_key.clone(),
+            voice_id: loaded.elevenlabs_voice_id.clone(),
+        }),
+        _ => Box::new(OpenAiTts {
+            api_key: loaded.openai_api_key.clone(),
+            voice: loaded.voice.clone(),
+        }),
+    }
+}
+
+/// Voices for the currently selected provider, for the settings panel.
+#[tauri::command]
+pub async fn list_tts_voices() -> Result<Vec<VoiceInfo>, String> {
+    let loaded = settings::load_settings()?;
+    provider_from_settings(&loaded).list_voices().await
+}
@@ -0,0 +1,166 @@
+//! Token usage ledger and analytics.
+//!
+//! An append-only JSONL ledger at `~/.claude/sentra/usage-ledger.jsonl`
+//! recording tokens and request counts per provider and model. The dashboard
+//! aggregates it per day to chart where the spend actually goes (agents vs.
+//! architect vs. TTS). Recording is best-effort: a failed write never fails
+//! the API call that produced the usage.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::settings;
+use crate::time_tracking;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageKind {
+    Architect,
+    Agent,
+    Tts,
+    Transcription,
+    Realtime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageEntry {
+    pub provider: String,
+    pub model: String,
+    pub kind: UsageKind,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Project name when the call was made on a project's behalf.
+    #[serde(default)]
+    pub project: Option<String>,
+    pub recorded_at: String,
+}
+
+fn ledger_path() -> Result<PathBuf, String> {
+    Ok(settings::sentra_dir()?.join("usage-ledger.jsonl"))
+}
+
+pub(crate) fn read_ledger() -> Result<Vec<UsageEntry>, String> {
+    let path = ledger_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Append one request's usage to the ledger.
+pub fn record(
+    provider: &str,
+    model: &str,
+    kind: UsageKind,
+    input_tokens: u64,
+    output_tokens: u64,
+    project: Option<&str>,
+) -> Result<(), String> {
+    let entry = UsageEntry {
+        provider: provider.to_string(),
+        model: model.to_string(),
+        kind,
+        input_tokens,
+        output_tokens,
+        project: project.map(|p| p.to_string()),
+        recorded_at: Utc::now().to_rfc3339(),
+    };
+    let path = ledger_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| e.to_string())?;
+    let json = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+    writeln!(file, "{}", json).map_err(|e| e.to_string())
+}
+
+/// One provider/model/kind on one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageBucket {
+    pub day: String,
+    pub provider: String,
+    pub model: String,
+    pub kind: UsageKind,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub requests: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAnalytics {
+    pub range: String,
+    pub buckets: Vec<UsageBucket>,
+    pub total_input_tokens: u64,
+    pub total_output_tokens: u64,
+    pub total_requests: u64,
+}
+
+/// Aggregate the usage ledger per provider/model/day over a range.
+#[tauri::command]
+pub fn get_usage_analytics(range: String) -> Result<UsageAnalytics, String> {
+    let start = time_tracking::range_start(&range, Utc::now())?;
+    let mut buckets: Vec<UsageBucket> = Vec::new();
+    let mut total_input = 0;
+    let mut total_output = 0;
+    let mut total_requests = 0;
+
+    for entry in read_ledger()? {
+        let Ok(recorded) = DateTime::parse_from_rfc3339(&entry.recorded_at) else {
+            continue;
+        };
+        if recorded.with_timezone(&Utc) < start {
+            continue;
+        }
+        let day = entry.recorded_at.chars().take(10).collect::<String>();
+        let slot = match buckets.iter_mut().find(|b| {
+            b.day == day
+                && b.provider == entry.provider
+                && b.model == entry.model
+                && b.kind == entry.kind
+        }) {
+            Some(slot) => slot,
+            None => {
+                buckets.push(UsageBucket {
+                    day,
+                    provider: entry.provider.clone(),
+                    model: entry.model.clone(),
+                    kind: entry.kind,
+                    input_tokens: 0,
+                    output_tokens: 0,
+                    requests: 0,
+                });
+                buckets.last_mut().unwrap()
+            }
+        };
+        slot.input_tokens += entry.input_tokens;
+        slot.output_tokens += entry.output_tokens;
+        slot.requests += 1;
+        total_input += entry.input_tokens;
+        total_output += entry.output_tokens;
+        total_requests += 1;
+    }
+
+    buckets.sort_by(|a, b| a.day.cmp(&b.day));
+    Ok(UsageAnalytics {
+        range,
+        buckets,
+        total_input_tokens: total_input,
+        total_output_tokens: total_output,
+        total_requests,
+    })
+}
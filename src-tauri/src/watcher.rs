@@ -0,0 +1,241 @@
+//! File watching.
+//!
+//! Watches the tracked-projects registry, the telemetry directory, and each
+//! tracked project's `.sentra/specs` and `.claude/memory` directories so the
+//! frontend refreshes when the runner or an external tool changes state.
+//! Per-project watches are re-registered whenever the tracked list changes,
+//! and the whole watch set is re-evaluated periodically so paths that did
+//! not exist at launch (a fresh registry, a project's first spec) get
+//! picked up without restarting the app.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, Debouncer};
+use tauri::{AppHandle, Emitter};
+
+use crate::commands;
+
+/// How often the watch set is re-evaluated when no events arrive, so
+/// paths created after launch start being watched.
+const RESYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Minimum gap between emits of the same event, so a burst of filesystem
+/// activity (an `npm install` under a watched tree) collapses into one
+/// frontend refresh instead of hundreds.
+const EMIT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// True when any path component matches one of the configured ignore
+/// patterns (`node_modules`, `target`, `.git` by default).
+pub fn is_ignored(path: &Path, patterns: &[String]) -> bool {
+    path.components().any(|c| {
+        let name = c.as_os_str().to_string_lossy();
+        patterns.iter().any(|p| name == p.as_str())
+    })
+}
+
+/// Tracks the last emit per event name and drops emits that arrive within
+/// [`EMIT_INTERVAL`] of the previous one.
+struct EmitLimiter {
+    last: HashMap<&'static str, Instant>,
+}
+
+impl EmitLimiter {
+    fn new() -> Self {
+        Self {
+            last: HashMap::new(),
+        }
+    }
+
+    fn emit(&mut self, app: &AppHandle, event: &'static str) {
+        let now = Instant::now();
+        if let Some(previous) = self.last.get(event) {
+            if now.duration_since(*previous) < EMIT_INTERVAL {
+                return;
+            }
+        }
+        self.last.insert(event, now);
+        let _ = app.emit(event, ());
+    }
+}
+
+/// What a filesystem event means to the frontend.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum WatchKind {
+    Specs,
+    Memory,
+}
+
+impl WatchKind {
+    fn event(self) -> &'static str {
+        match self {
+            WatchKind::Specs => "specs-updated",
+            WatchKind::Memory => "memory-updated",
+        }
+    }
+}
+
+/// Start the global file watcher. Spawned through [`crate::lifecycle`],
+/// which flips `stop` when the watcher should shut down or be replaced.
+pub fn start_file_watcher(app: AppHandle, stop: Arc<AtomicBool>) {
+    std::thread::spawn(move || {
+        if let Err(e) = run_watcher(app, &stop) {
+            log::error!("File watcher failed to start: {}", e);
+        }
+    });
+}
+
+/// The directories inside `project` worth watching, with the event each
+/// one maps to.
+fn project_watch_dirs(project: &std::path::Path) -> Vec<(PathBuf, WatchKind)> {
+    vec![
+        (project.join(".sentra").join("specs"), WatchKind::Specs),
+        (project.join(".claude").join("memory"), WatchKind::Memory),
+    ]
+}
+
+/// Bring the per-project watch set in line with the tracked-projects
+/// registry: drop watches for projects no longer tracked, add watches for
+/// new ones. Directories that don't exist yet are skipped.
+fn sync_project_watches(
+    debouncer: &mut Debouncer<RecommendedWatcher>,
+    watched: &mut HashMap<PathBuf, WatchKind>,
+) {
+    let projects = commands::read_tracked_projects().unwrap_or_default();
+    let mut desired: HashMap<PathBuf, WatchKind> = HashMap::new();
+    for project in &projects {
+        for (dir, kind) in project_watch_dirs(project) {
+            if dir.exists() {
+                desired.insert(dir, kind);
+            }
+        }
+    }
+
+    let stale: Vec<PathBuf> = watched
+        .keys()
+        .filter(|dir| !desired.contains_key(*dir))
+        .cloned()
+        .collect();
+    for dir in stale {
+        let _ = debouncer.watcher().unwatch(&dir);
+        watched.remove(&dir);
+    }
+    for (dir, kind) in desired {
+        if watched.contains_key(&dir) {
+            continue;
+        }
+        match debouncer.watcher().watch(&dir, RecursiveMode::Recursive) {
+            Ok(()) => {
+                watched.insert(dir, kind);
+            }
+            Err(e) => log::warn!("Failed to watch {}: {}", dir.display(), e),
+        }
+    }
+}
+
+fn run_watcher(app: AppHandle, stop: &AtomicBool) -> Result<(), String> {
+    let tracked_file = commands::tracked_projects_file()?;
+    let telemetry = commands::telemetry_dir()?;
+    let ignore = crate::settings::load_settings()
+        .unwrap_or_default()
+        .watcher_ignore;
+
+    // The debouncer callback runs on notify's thread and can't touch the
+    // watcher, so it just forwards paths; this thread classifies them and
+    // adjusts the watch set.
+    let (tx, rx) = mpsc::channel::<Vec<PathBuf>>();
+    let mut debouncer = new_debouncer(Duration::from_millis(500), move |events| {
+        if let Ok(events) = events {
+            let paths = events
+                .into_iter()
+                .map(|e: notify_debouncer_mini::DebouncedEvent| e.path)
+                .collect();
+            let _ = tx.send(paths);
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    let mut registry_watched = false;
+    let mut telemetry_watched = false;
+    let mut watched: HashMap<PathBuf, WatchKind> = HashMap::new();
+    sync_root_watches(
+        &mut debouncer,
+        &tracked_file,
+        &mut registry_watched,
+        &telemetry,
+        &mut telemetry_watched,
+    );
+    sync_project_watches(&mut debouncer, &mut watched);
+
+    // Polling with a short timeout keeps shutdown latency low; resyncs
+    // still only happen every RESYNC_INTERVAL.
+    let mut limiter = EmitLimiter::new();
+    let mut last_resync = Instant::now();
+    while !stop.load(Ordering::Relaxed) {
+        match rx.recv_timeout(Duration::from_millis(500)) {
+            Ok(paths) => {
+                for path in paths {
+                    if is_ignored(&path, &ignore) {
+                        continue;
+                    }
+                    if path == tracked_file {
+                        commands::invalidate_projects_cache();
+                        limiter.emit(&app, "projects-updated");
+                        sync_project_watches(&mut debouncer, &mut watched);
+                    } else if let Some(kind) = watched
+                        .iter()
+                        .find(|(dir, _)| path.starts_with(dir))
+                        .map(|(_, kind)| *kind)
+                    {
+                        limiter.emit(&app, kind.event());
+                    } else {
+                        limiter.emit(&app, "telemetry-updated");
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if last_resync.elapsed() >= RESYNC_INTERVAL {
+                    sync_root_watches(
+                        &mut debouncer,
+                        &tracked_file,
+                        &mut registry_watched,
+                        &telemetry,
+                        &mut telemetry_watched,
+                    );
+                    sync_project_watches(&mut debouncer, &mut watched);
+                    last_resync = Instant::now();
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Watch the registry file and telemetry directory once they exist. Either
+/// may be missing on a fresh install, so this is retried on every resync
+/// pass until both are registered.
+fn sync_root_watches(
+    debouncer: &mut Debouncer<RecommendedWatcher>,
+    tracked_file: &std::path::Path,
+    registry_watched: &mut bool,
+    telemetry: &std::path::Path,
+    telemetry_watched: &mut bool,
+) {
+    if !*registry_watched && tracked_file.exists() {
+        *registry_watched = debouncer
+            .watcher()
+            .watch(tracked_file, RecursiveMode::NonRecursive)
+            .is_ok();
+    }
+    if !*telemetry_watched && telemetry.exists() {
+        *telemetry_watched = debouncer
+            .watcher()
+            .watch(telemetry, RecursiveMode::Recursive)
+            .is_ok();
+    }
+}
@@ -0,0 +1,255 @@
+//! Embedded GitHub webhook listener.
+//!
+//! An optional HTTP listener that turns pushed webhook deliveries
+//! (workflow_run, pull_request, issues) into activity events and frontend
+//! emits, instead of polling gh every few seconds and burning the rate
+//! limit. Enabled by setting `webhook_port` in settings; deliveries are
+//! verified against `webhook_secret` when one is configured.
+
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::activity::{self, ActivityEventType};
+
+/// Payloads beyond this are rejected; real GitHub deliveries are far
+/// smaller.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Accept webhook deliveries forever. Spawned from setup when a port is
+/// configured.
+pub async fn start_webhook_listener(app: AppHandle, port: u16, secret: String) {
+    let addr = format!("0.0.0.0:{}", port);
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Webhook listener failed to bind {}: {}", addr, e);
+            return;
+        }
+    };
+    if secret.is_empty() {
+        log::warn!("Webhook listener running without signature verification; set webhookSecret");
+    }
+    log::info!("Webhook listener on {}", addr);
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let app = app.clone();
+                let secret = secret.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(app, stream, &secret).await {
+                        log::debug!("Webhook connection error: {}", e);
+                    }
+                });
+            }
+            Err(e) => log::warn!("Webhook accept failed: {}", e),
+        }
+    }
+}
+
+async fn handle_connection(
+    app: AppHandle,
+    mut stream: TcpStream,
+    secret: &str,
+) -> Result<(), String> {
+    let (head, body) = read_request(&mut stream).await?;
+    let header = |name: &str| -> Option<String> {
+        head.lines()
+            .skip(1)
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case(name)
+                    .then(|| value.trim().to_string())
+            })
+    };
+
+    let request_line = head.lines().next().unwrap_or_default();
+    if !request_line.starts_with("POST /webhook") {
+        return respond(&mut stream, "404 Not Found").await;
+    }
+    if !secret.is_empty() {
+        let signature = header("X-Hub-Signature-256").unwrap_or_default();
+        if !verify_signature(secret, &body, &signature) {
+            return respond(&mut stream, "401 Unauthorized").await;
+        }
+    }
+
+    let event = header("X-GitHub-Event").unwrap_or_default();
+    let payload: Value = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+    if let Some((event_type, project, message)) = event_to_activity(&event, &payload) {
+        activity::record_event(event_type, &project, &message);
+        let _ = app.emit("activity-updated", ());
+        let _ = app.emit(
+            "webhook-event",
+            serde_json::json!({
+                "event": event,
+                "action": payload.get("action").and_then(|a| a.as_str()).unwrap_or(""),
+                "project": project,
+                "message": message,
+            }),
+        );
+    }
+
+    respond(&mut stream, "204 No Content").await
+}
+
+/// Read one HTTP request: headers up to the blank line, then exactly
+/// Content-Length body bytes.
+async fn read_request(stream: &mut TcpStream) -> Result<(String, Vec<u8>), String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 8192];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.map_err(|e| e.to_string())?;
+        if n == 0 {
+            return Err("Connection closed mid-request".to_string());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        if buf.len() > MAX_BODY_BYTES {
+            return Err("Request headers too large".to_string());
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length: usize = head
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim()
+                .eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse().ok())?
+        })
+        .unwrap_or(0);
+    if content_length > MAX_BODY_BYTES {
+        return Err("Request body too large".to_string());
+    }
+
+    let mut body = buf[header_end..].to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await.map_err(|e| e.to_string())?;
+        if n == 0 {
+            return Err("Connection closed mid-body".to_string());
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+    Ok((head, body))
+}
+
+async fn respond(stream: &mut TcpStream, status: &str) -> Result<(), String> {
+    let response = format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status);
+    stream
+        .write_all(response.as_bytes())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Check a delivery's `X-Hub-Signature-256` header ("sha256=<hex>")
+/// against the HMAC of the body. Comparison is constant-time.
+pub fn verify_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    let Some(hex) = signature_header.strip_prefix("sha256=") else {
+        return false;
+    };
+    let mut mac = match Hmac::<Sha256>::new_from_slice(secret.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.update(body);
+    let expected: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    expected.len() == hex.len()
+        && expected
+            .bytes()
+            .zip(hex.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b.to_ascii_lowercase()))
+            == 0
+}
+
+/// Map a webhook payload onto the activity feed. Events the feed doesn't
+/// track return None and are acknowledged without recording anything.
+pub fn event_to_activity(event: &str, payload: &Value) -> Option<(ActivityEventType, String, String)> {
+    let str_at = |pointer: &str| -> &str {
+        payload.pointer(pointer).and_then(|v| v.as_str()).unwrap_or("")
+    };
+    let project = str_at("/repository/name").to_string();
+    let action = str_at("/action");
+
+    match event {
+        "workflow_run" => {
+            let name = str_at("/workflow_run/name");
+            let branch = str_at("/workflow_run/head_branch");
+            match action {
+                "requested" => Some((
+                    ActivityEventType::AgentStart,
+                    project,
+                    format!("Agent started: {} on {}", name, branch),
+                )),
+                "completed" => {
+                    let (event_type, verb) = match str_at("/workflow_run/conclusion") {
+                        "success" => (ActivityEventType::AgentComplete, "completed"),
+                        _ => (ActivityEventType::Error, "failed"),
+                    };
+                    Some((
+                        event_type,
+                        project,
+                        format!("Agent {}: {} on {}", verb, name, branch),
+                    ))
+                }
+                _ => None,
+            }
+        }
+        "pull_request" => {
+            let number = payload.pointer("/pull_request/number").and_then(|n| n.as_u64())?;
+            let title = str_at("/pull_request/title");
+            match action {
+                "opened" => Some((
+                    ActivityEventType::PrOpened,
+                    project,
+                    format!("PR #{} opened: {}", number, title),
+                )),
+                "closed"
+                    if payload
+                        .pointer("/pull_request/merged")
+                        .and_then(|m| m.as_bool())
+                        .unwrap_or(false) =>
+                {
+                    Some((
+                        ActivityEventType::PrMerged,
+                        project,
+                        format!("PR #{} merged: {}", number, title),
+                    ))
+                }
+                _ => None,
+            }
+        }
+        "issues" => {
+            let number = payload.pointer("/issue/number").and_then(|n| n.as_u64())?;
+            let title = str_at("/issue/title");
+            match action {
+                "opened" => Some((
+                    ActivityEventType::IssueOpened,
+                    project,
+                    format!("Issue #{} opened: {}", number, title),
+                )),
+                "closed" => Some((
+                    ActivityEventType::IssueClosed,
+                    project,
+                    format!("Issue #{} closed: {}", number, title),
+                )),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,18 @@
+use sentra_lib::agent_stream::{get_active_streams, stop_agent_stream, AgentStreamUpdate};
+
+#[test]
+fn stream_update_serializes_camel_case() {
+    let update = AgentStreamUpdate {
+        agent_id: "12345".to_string(),
+        chunk: "compiling...\n".to_string(),
+    };
+    let json = serde_json::to_value(&update).unwrap();
+    assert_eq!(json["agentId"], "12345");
+    assert_eq!(json["chunk"], "compiling...\n");
+}
+
+#[test]
+fn stopping_an_unknown_stream_is_harmless() {
+    stop_agent_stream("no-such-agent".to_string()).unwrap();
+    assert!(get_active_streams().unwrap().is_empty());
+}
@@ -0,0 +1,57 @@
+use sentra_lib::architect::{
+    estimate_tokens, sniff_audio_format, trim_to_context_window, ChatMessage,
+};
+
+fn message(role: &str, content: &str) -> ChatMessage {
+    ChatMessage {
+        role: role.to_string(),
+        content: content.to_string(),
+    }
+}
+
+#[test]
+fn audio_formats_are_sniffed_from_magic_bytes() {
+    assert_eq!(sniff_audio_format(b"RIFF\x00\x00\x00\x00WAVEfmt ").0, "audio.wav");
+    assert_eq!(sniff_audio_format(b"OggS\x00\x02").0, "audio.ogg");
+    assert_eq!(sniff_audio_format(b"\x00\x00\x00\x20ftypM4A ").0, "audio.m4a");
+    assert_eq!(sniff_audio_format(b"\x1a\x45\xdf\xa3").0, "audio.webm");
+    // Unknown data falls back to webm, the browser default.
+    assert_eq!(sniff_audio_format(b"??").0, "audio.webm");
+}
+
+#[test]
+fn token_estimate_scales_with_length() {
+    assert_eq!(estimate_tokens(""), 0);
+    assert_eq!(estimate_tokens("abcd"), 1);
+    assert!(estimate_tokens(&"x".repeat(4000)) >= 1000);
+}
+
+#[test]
+fn short_conversations_pass_through_untrimmed() {
+    let messages = vec![message("user", "hello"), message("assistant", "hi")];
+    let trimmed = trim_to_context_window("system", &messages);
+    assert_eq!(trimmed.len(), 2);
+    assert_eq!(trimmed[0].content, "hello");
+}
+
+#[test]
+fn oversized_conversations_get_summarized() {
+    // Each message is ~25k tokens; 40 of them blow the 200k window.
+    let big = "word ".repeat(20_000);
+    let mut messages = Vec::new();
+    for i in 0..40 {
+        messages.push(message(
+            if i % 2 == 0 { "user" } else { "assistant" },
+            &format!("turn {}\n{}", i, big),
+        ));
+    }
+
+    let trimmed = trim_to_context_window("system", &messages);
+    assert!(trimmed.len() < messages.len());
+    // Oldest turns collapse into a leading summary message.
+    assert_eq!(trimmed[0].role, "user");
+    assert!(trimmed[0].content.contains("Summary of earlier discussion"));
+    assert!(trimmed[0].content.contains("turn 0"));
+    // The newest message survives verbatim.
+    assert!(trimmed.last().unwrap().content.starts_with("turn 39"));
+}
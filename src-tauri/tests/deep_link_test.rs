@@ -0,0 +1,37 @@
+use sentra_lib::deep_link::{parse_deep_link, NavigationTarget};
+
+#[test]
+fn parses_project_and_spec_links() {
+    assert_eq!(
+        parse_deep_link("sentra://project/aidio").unwrap(),
+        NavigationTarget::Project {
+            name: "aidio".to_string()
+        }
+    );
+    assert_eq!(
+        parse_deep_link("sentra://project/aidio/spec/user-auth").unwrap(),
+        NavigationTarget::Spec {
+            project: "aidio".to_string(),
+            spec_id: "user-auth".to_string()
+        }
+    );
+}
+
+#[test]
+fn parses_pr_links() {
+    assert_eq!(
+        parse_deep_link("sentra://pr/barnent1/sentra/123").unwrap(),
+        NavigationTarget::PullRequest {
+            owner: "barnent1".to_string(),
+            repo: "sentra".to_string(),
+            number: 123
+        }
+    );
+}
+
+#[test]
+fn rejects_malformed_links() {
+    assert!(parse_deep_link("https://example.com").is_err());
+    assert!(parse_deep_link("sentra://pr/owner/repo/not-a-number").is_err());
+    assert!(parse_deep_link("sentra://unknown/thing").is_err());
+}
@@ -0,0 +1,69 @@
+//! Parsing tests for git plumbing output.
+
+use sentra_lib::git::parse_blame;
+use sentra_lib::git_worktrees::parse_worktrees;
+
+#[test]
+fn blame_porcelain_is_parsed_per_line() {
+    let hash = "1234567890abcdef1234567890abcdef12345678";
+    let output = format!(
+        "{hash} 1 1 2\n\
+         author Alice\n\
+         author-mail <alice@example.com>\n\
+         author-time 1700000000\n\
+         author-tz +0000\n\
+         summary add greeting\n\
+         filename hello.rs\n\
+         \tfn main() {{\n\
+         {hash} 2 2\n\
+         author Alice\n\
+         author-mail <alice@example.com>\n\
+         author-time 1700000000\n\
+         author-tz +0000\n\
+         summary add greeting\n\
+         filename hello.rs\n\
+         \t}}\n"
+    );
+
+    let lines = parse_blame(&output);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].line, 1);
+    assert_eq!(lines[0].hash, hash);
+    assert_eq!(lines[0].author, "Alice");
+    assert!(lines[0].date.starts_with("2023-11-14"));
+    assert_eq!(lines[0].content, "fn main() {");
+    assert_eq!(lines[1].line, 2);
+    assert_eq!(lines[1].content, "}");
+}
+
+#[test]
+fn blame_of_empty_output_is_empty() {
+    assert!(parse_blame("").is_empty());
+}
+
+#[test]
+fn worktree_porcelain_blocks_are_parsed() {
+    let output = "worktree /repo\n\
+                  HEAD aaaa\n\
+                  branch refs/heads/main\n\
+                  \n\
+                  worktree /worktrees/agent-1\n\
+                  HEAD bbbb\n\
+                  branch refs/heads/agent/fix-login\n\
+                  locked agent running\n\
+                  \n\
+                  worktree /worktrees/gone\n\
+                  HEAD cccc\n\
+                  detached\n\
+                  prunable\n";
+
+    let worktrees = parse_worktrees(output);
+    assert_eq!(worktrees.len(), 3);
+    assert_eq!(worktrees[0].branch.as_deref(), Some("main"));
+    assert!(!worktrees[0].locked);
+    assert_eq!(worktrees[1].path, "/worktrees/agent-1");
+    assert_eq!(worktrees[1].branch.as_deref(), Some("agent/fix-login"));
+    assert!(worktrees[1].locked);
+    assert_eq!(worktrees[2].branch, None);
+    assert!(worktrees[2].prunable);
+}
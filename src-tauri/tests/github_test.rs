@@ -0,0 +1,41 @@
+//! Tests for the native GitHub client's remote-URL parsing.
+
+use sentra_lib::github::parse_repo_slug;
+
+#[test]
+fn common_remote_url_forms_parse_to_a_slug() {
+    for url in [
+        "https://github.com/barnent1/sentra.git",
+        "https://github.com/barnent1/sentra",
+        "git@github.com:barnent1/sentra.git",
+        "ssh://git@github.com/barnent1/sentra",
+    ] {
+        assert_eq!(
+            parse_repo_slug(url).as_deref(),
+            Some("barnent1/sentra"),
+            "failed for {}",
+            url
+        );
+    }
+}
+
+#[test]
+fn forge_remote_urls_parse_to_repo_paths() {
+    use sentra_lib::git_provider::slug_from_remote_url;
+    assert_eq!(
+        slug_from_remote_url("https://gitlab.example.com/group/sub/repo.git").as_deref(),
+        Some("group/sub/repo")
+    );
+    assert_eq!(
+        slug_from_remote_url("git@gitea.example.com:owner/repo.git").as_deref(),
+        Some("owner/repo")
+    );
+    assert_eq!(slug_from_remote_url("https://example.com/repo-only"), None);
+}
+
+#[test]
+fn non_github_remotes_are_rejected() {
+    assert_eq!(parse_repo_slug("https://gitlab.com/owner/repo.git"), None);
+    assert_eq!(parse_repo_slug("https://github.com/owner-only"), None);
+    assert_eq!(parse_repo_slug(""), None);
+}
@@ -0,0 +1,46 @@
+use sentra_lib::mcp::handle_request;
+use serde_json::json;
+
+#[test]
+fn initialize_reports_tool_capability() {
+    let response = handle_request(&json!({
+        "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}
+    }))
+    .unwrap();
+    assert_eq!(response["id"], 1);
+    assert!(response["result"]["capabilities"]["tools"].is_object());
+    assert_eq!(response["result"]["serverInfo"]["name"], "sentra");
+}
+
+#[test]
+fn tools_list_includes_core_tools() {
+    let response = handle_request(&json!({
+        "jsonrpc": "2.0", "id": 2, "method": "tools/list"
+    }))
+    .unwrap();
+    let tools = response["result"]["tools"].as_array().unwrap();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    assert!(names.contains(&"list_specs"));
+    assert!(names.contains(&"get_project_memory"));
+    assert!(names.contains(&"get_git_status"));
+}
+
+#[test]
+fn notifications_get_no_response() {
+    assert!(handle_request(&json!({
+        "jsonrpc": "2.0", "method": "notifications/initialized"
+    }))
+    .is_none());
+}
+
+#[test]
+fn unknown_methods_return_an_error() {
+    let response = handle_request(&json!({
+        "jsonrpc": "2.0", "id": 3, "method": "resources/list"
+    }))
+    .unwrap();
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Method not found"));
+}
@@ -0,0 +1,58 @@
+use sentra_lib::memory::{read_all, read_section, write_section, MemorySection, WriteMode};
+
+fn temp_project(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("sentra-memory-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn append_and_replace_modes() {
+    let dir = temp_project("modes");
+
+    write_section(&dir, MemorySection::Gotchas, "- first", WriteMode::Append).unwrap();
+    write_section(&dir, MemorySection::Gotchas, "- second", WriteMode::Append).unwrap();
+    let content = read_section(&dir, MemorySection::Gotchas);
+    assert!(content.contains("- first"));
+    assert!(content.contains("- second"));
+
+    write_section(&dir, MemorySection::Gotchas, "- only", WriteMode::Replace).unwrap();
+    assert_eq!(read_section(&dir, MemorySection::Gotchas), "- only");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn parses_entries_with_dates_and_tags() {
+    let content = "intro line\n\n### Watch out for tokio blocking (2025-11-20)\nDetails here.\nTags: tokio, async\n\n### Another note\nBody only.\n";
+    let (preamble, entries) = sentra_lib::memory::parse_entries(content);
+    assert_eq!(preamble, "intro line");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].date.as_deref(), Some("2025-11-20"));
+    assert_eq!(entries[0].tags, vec!["tokio", "async"]);
+    assert_eq!(entries[1].heading, "Another note");
+    assert!(entries[1].date.is_none());
+    assert!(entries[1].tags.is_empty());
+}
+
+#[test]
+fn non_ascii_headings_do_not_break_date_parsing() {
+    let content = "### Décision sur l'architecture 2024-05-01\nRésumé — détails à venir.\n";
+    let (_, entries) = sentra_lib::memory::parse_entries(content);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].date.as_deref(), Some("2024-05-01"));
+}
+
+#[test]
+fn read_all_labels_sections() {
+    let dir = temp_project("labels");
+    write_section(&dir, MemorySection::Patterns, "- use X", WriteMode::Replace).unwrap();
+
+    let all = read_all(&dir);
+    assert!(all.contains("## patterns"));
+    assert!(all.contains("- use X"));
+    // Empty sections are omitted entirely.
+    assert!(!all.contains("## gotchas"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
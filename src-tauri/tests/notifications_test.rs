@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use sentra_lib::notifications::{render_template, template_for, window_covers, NotificationType};
+use sentra_lib::settings::{QuietWindow, Settings};
+
+#[test]
+fn renders_variables_into_templates() {
+    let mut vars = HashMap::new();
+    vars.insert("project".to_string(), "aidio".to_string());
+    vars.insert("issue".to_string(), "42".to_string());
+    assert_eq!(
+        render_template("Agent for {project} failed on issue {issue}.", &vars),
+        "Agent for aidio failed on issue 42."
+    );
+}
+
+#[test]
+fn unknown_placeholders_are_left_visible() {
+    let vars = HashMap::new();
+    assert_eq!(render_template("Hello {nobody}", &vars), "Hello {nobody}");
+}
+
+#[test]
+fn settings_overrides_take_precedence_over_defaults() {
+    let mut settings = Settings::default();
+    assert!(template_for(&settings, NotificationType::AgentComplete).contains("{project}"));
+
+    settings.notification_templates.insert(
+        "agent_complete".to_string(),
+        "{project} is done!".to_string(),
+    );
+    assert_eq!(
+        template_for(&settings, NotificationType::AgentComplete),
+        "{project} is done!"
+    );
+}
+
+#[test]
+fn quiet_windows_match_days_and_wrap_midnight() {
+    let weeknights = QuietWindow {
+        days: vec!["mon".to_string(), "tue".to_string()],
+        start: "22:00".to_string(),
+        end: "07:00".to_string(),
+    };
+    // Monday 23:00 is inside; Monday noon is not.
+    assert!(window_covers(&weeknights, "mon", 23 * 60));
+    assert!(!window_covers(&weeknights, "mon", 12 * 60));
+    // The wrap lands on Tuesday morning, not Thursday morning.
+    assert!(window_covers(&weeknights, "tue", 6 * 60));
+    assert!(!window_covers(&weeknights, "thu", 6 * 60));
+
+    let daily = QuietWindow {
+        days: Vec::new(),
+        start: "12:00".to_string(),
+        end: "13:00".to_string(),
+    };
+    assert!(window_covers(&daily, "sat", 12 * 60 + 30));
+    assert!(!window_covers(&daily, "sat", 13 * 60));
+}
@@ -0,0 +1,8 @@
+use sentra_lib::performance;
+
+#[test]
+fn mark_started_is_idempotent() {
+    performance::mark_started();
+    // A second call must not panic or reset the start time.
+    performance::mark_started();
+}
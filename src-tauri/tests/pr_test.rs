@@ -0,0 +1,22 @@
+use sentra_lib::pr::{codeowners_matches, owners_for_path};
+
+#[test]
+fn codeowners_pattern_forms() {
+    assert!(codeowners_matches("*", "anything/at/all.ts"));
+    assert!(codeowners_matches("*.ts", "src/app/page.ts"));
+    assert!(!codeowners_matches("*.ts", "src/app/page.tsx"));
+    assert!(codeowners_matches("src/app/", "src/app/page.tsx"));
+    assert!(codeowners_matches("/docs", "docs/README.md"));
+    assert!(!codeowners_matches("src/app/", "src/lib/util.ts"));
+}
+
+#[test]
+fn last_matching_rule_wins() {
+    let codeowners = "# comment\n* @fallback\nsrc/ @glen @sam\nsrc/voice/ @voice-team\n";
+    assert_eq!(owners_for_path(codeowners, "README.md"), vec!["fallback"]);
+    assert_eq!(owners_for_path(codeowners, "src/lib/a.ts"), vec!["glen", "sam"]);
+    assert_eq!(
+        owners_for_path(codeowners, "src/voice/echo.ts"),
+        vec!["voice-team"]
+    );
+}
@@ -0,0 +1,76 @@
+use sentra_lib::commands;
+use sentra_lib::git;
+use sentra_lib::specs;
+
+#[test]
+fn slugify_produces_filesystem_safe_ids() {
+    assert_eq!(specs::slugify("User Authentication"), "user-authentication");
+    assert_eq!(specs::slugify("  Spaced  Out!  "), "spaced-out");
+    assert_eq!(specs::slugify("API v2 (draft)"), "api-v2-draft");
+}
+
+#[test]
+fn parse_log_splits_on_field_separator() {
+    let output = "abc123\u{1f}Glen\u{1f}2025-11-01T10:00:00-05:00\u{1f}feat: add thing\n\
+                  def456\u{1f}Agent\u{1f}2025-11-01T09:00:00-05:00\u{1f}fix: a bug";
+    let commits = git::parse_log(output);
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].hash, "abc123");
+    assert_eq!(commits[0].author, "Glen");
+    assert_eq!(commits[1].message, "fix: a bug");
+}
+
+#[test]
+fn parse_log_ignores_malformed_lines() {
+    let commits = git::parse_log("not-a-commit-line");
+    assert!(commits.is_empty());
+}
+
+#[test]
+fn line_diff_marks_changes() {
+    let old = "# Title\nkeep\nremove me\n";
+    let new = "# Title\nkeep\nadded line\n";
+    let diff = specs::line_diff(old, new);
+    assert!(diff.contains("  # Title"));
+    assert!(diff.contains("- remove me"));
+    assert!(diff.contains("+ added line"));
+    assert_eq!(specs::line_diff("same\n", "same\n"), "  same\n");
+}
+
+#[test]
+fn commit_subject_validation() {
+    let rules = git::CommitRules::default();
+    assert!(git::validate_commit_subject("feat(voice): add echo cancellation", &rules).is_empty());
+    assert!(git::validate_commit_subject("fix: a bug", &rules).is_empty());
+    assert!(git::validate_commit_subject("Merge branch 'main'", &rules).is_empty());
+
+    assert!(!git::validate_commit_subject("updated stuff", &rules).is_empty());
+    assert!(!git::validate_commit_subject("wip: thing", &rules).is_empty());
+    assert!(!git::validate_commit_subject("feat:", &rules).is_empty());
+
+    let strict = git::CommitRules {
+        require_scope: true,
+        ..git::CommitRules::default()
+    };
+    assert!(!git::validate_commit_subject("feat: no scope", &strict).is_empty());
+}
+
+#[test]
+fn context_tree_respects_gitignore_and_counts_files() {
+    let dir = std::env::temp_dir().join(format!("sentra-tree-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("src/app/login")).unwrap();
+    std::fs::create_dir_all(dir.join("dist")).unwrap();
+    std::fs::write(dir.join(".gitignore"), "dist/\n*.log\n").unwrap();
+    std::fs::write(dir.join("src/app/login/page.tsx"), "export {}").unwrap();
+    std::fs::write(dir.join("debug.log"), "noise").unwrap();
+    std::fs::write(dir.join("dist/bundle.js"), "built").unwrap();
+
+    let sections = commands::get_project_context_sections(dir.display().to_string()).unwrap();
+    let tree = &sections.last().unwrap().content;
+    assert!(tree.contains("- src/ (1 files)"));
+    assert!(tree.contains("  - app/ (1 files)"));
+    assert!(!tree.contains("dist"));
+    assert!(!tree.contains("debug.log"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
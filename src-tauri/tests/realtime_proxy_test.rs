@@ -0,0 +1,30 @@
+//! Tests for the realtime proxy's connection authorization helpers.
+
+use sentra_lib::realtime_proxy::{origin_allowed, query_has_token, session_token};
+
+#[test]
+fn session_token_is_stable_within_a_run() {
+    let token = session_token();
+    assert!(!token.is_empty());
+    assert_eq!(token, session_token());
+}
+
+#[test]
+fn token_queries_are_matched_exactly() {
+    assert!(query_has_token(Some("token=abc"), "abc"));
+    assert!(query_has_token(Some("model=gpt&token=abc"), "abc"));
+    assert!(!query_has_token(Some("token=abcd"), "abc"));
+    assert!(!query_has_token(Some("token=ab"), "abc"));
+    assert!(!query_has_token(Some("other=abc"), "abc"));
+    assert!(!query_has_token(None, "abc"));
+}
+
+#[test]
+fn only_app_origins_are_allowed() {
+    assert!(origin_allowed("tauri://localhost"));
+    assert!(origin_allowed("http://tauri.localhost"));
+    assert!(origin_allowed("http://localhost:3000"));
+    assert!(!origin_allowed("http://localhost:3001"));
+    assert!(!origin_allowed("https://evil.example.com"));
+    assert!(!origin_allowed(""));
+}
@@ -0,0 +1,59 @@
+use sentra_lib::settings::{migrate_settings_value, Settings, SETTINGS_SCHEMA_VERSION};
+
+#[test]
+fn default_settings_have_voice_enabled() {
+    let settings = Settings::default();
+    assert!(settings.voice_notifications_enabled);
+    assert_eq!(settings.voice, "nova");
+    assert!(settings.openai_api_key.is_empty());
+}
+
+#[test]
+fn settings_roundtrip_through_json() {
+    let mut settings = Settings::default();
+    settings.openai_api_key = "sk-test".to_string();
+    settings.voice = "alloy".to_string();
+
+    let json = serde_json::to_string(&settings).unwrap();
+    let parsed: Settings = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.openai_api_key, "sk-test");
+    assert_eq!(parsed.voice, "alloy");
+}
+
+#[test]
+fn settings_tolerate_missing_fields() {
+    // Old settings files predate several fields; they must still parse.
+    let parsed: Settings = serde_json::from_str(r#"{"openaiApiKey": "sk-old"}"#).unwrap();
+    assert_eq!(parsed.openai_api_key, "sk-old");
+    assert!(parsed.voice_notifications_enabled);
+}
+
+#[test]
+fn unversioned_settings_migrate_to_current_schema() {
+    let mut value = serde_json::json!({
+        "voice": "alloy",
+        "quietHoursStart": "22:00",
+        "quietHoursEnd": "07:00",
+    });
+    assert!(migrate_settings_value(&mut value));
+    assert_eq!(value["schemaVersion"], SETTINGS_SCHEMA_VERSION);
+    assert!(value.get("quietHoursStart").is_none());
+
+    let parsed: Settings = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.quiet_hours.len(), 1);
+    assert_eq!(parsed.quiet_hours[0].start, "22:00");
+    assert_eq!(parsed.quiet_hours[0].end, "07:00");
+}
+
+#[test]
+fn current_schema_migration_is_a_no_op() {
+    let mut value = serde_json::to_value(Settings::default()).unwrap();
+    assert!(!migrate_settings_value(&mut value));
+}
+
+#[test]
+fn newer_schema_is_left_untouched() {
+    let mut value = serde_json::json!({ "schemaVersion": SETTINGS_SCHEMA_VERSION + 1 });
+    assert!(!migrate_settings_value(&mut value));
+    assert_eq!(value["schemaVersion"], SETTINGS_SCHEMA_VERSION + 1);
+}
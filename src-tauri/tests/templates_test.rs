@@ -0,0 +1,36 @@
+use sentra_lib::templates;
+
+#[test]
+fn builtin_templates_are_complete() {
+    let all = templates::builtin_templates();
+    let names: Vec<&str> = all.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["nextjs", "python", "node", "rust", "go"]);
+
+    for template in &all {
+        assert!(!template.files.is_empty(), "{} has no files", template.name);
+        assert!(
+            template.files.iter().any(|f| f.path == "CLAUDE.md"),
+            "{} is missing CLAUDE.md",
+            template.name
+        );
+    }
+}
+
+#[test]
+fn apply_template_writes_files() {
+    let dir = std::env::temp_dir().join(format!("sentra-template-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    templates::apply_template(&dir, "python").unwrap();
+    assert!(dir.join("pyproject.toml").exists());
+    assert!(dir.join("CLAUDE.md").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn apply_unknown_template_fails() {
+    let dir = std::env::temp_dir();
+    let err = templates::apply_template(&dir, "does-not-exist").unwrap_err();
+    assert!(err.contains("Unknown template"));
+}
@@ -0,0 +1,22 @@
+//! Tests for watcher ignore matching.
+
+use std::path::Path;
+
+use sentra_lib::watcher::is_ignored;
+
+#[test]
+fn ignored_components_match_anywhere_in_the_path() {
+    let patterns: Vec<String> = ["node_modules", "target", ".git"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert!(is_ignored(
+        Path::new("/p/app/node_modules/react/index.js"),
+        &patterns
+    ));
+    assert!(is_ignored(Path::new("/p/app/target/debug/build"), &patterns));
+    assert!(is_ignored(Path::new("/p/app/.git/index.lock"), &patterns));
+    assert!(!is_ignored(Path::new("/p/app/src/main.rs"), &patterns));
+    // Patterns match whole components, not substrings.
+    assert!(!is_ignored(Path::new("/p/app/retargeting/a.ts"), &patterns));
+}
@@ -0,0 +1,63 @@
+//! Tests for webhook signature verification and event mapping.
+
+use sentra_lib::activity::ActivityEventType;
+use sentra_lib::webhooks::{event_to_activity, verify_signature};
+
+#[test]
+fn valid_signatures_are_accepted() {
+    let body = br#"{"zen":"Design for failure."}"#;
+    let header = "sha256=14e30a088eca4c13690b25f745bac78ab817b03fa09db4f793b065812383fb29";
+    assert!(verify_signature("supersecret", body, header));
+    // GitHub sends lowercase hex, but uppercase must verify too.
+    assert!(verify_signature("supersecret", body, &header.to_uppercase().replace("SHA256=", "sha256=")));
+}
+
+#[test]
+fn bad_signatures_are_rejected() {
+    let body = br#"{"zen":"Design for failure."}"#;
+    let good = "sha256=14e30a088eca4c13690b25f745bac78ab817b03fa09db4f793b065812383fb29";
+    assert!(!verify_signature("wrong-secret", body, good));
+    assert!(!verify_signature("supersecret", b"tampered", good));
+    assert!(!verify_signature("supersecret", body, "sha256=abcd"));
+    assert!(!verify_signature("supersecret", body, "sha1=whatever"));
+    assert!(!verify_signature("supersecret", body, ""));
+}
+
+#[test]
+fn workflow_run_events_map_to_agent_activity() {
+    let payload = serde_json::json!({
+        "action": "completed",
+        "repository": { "name": "sentra" },
+        "workflow_run": {
+            "name": "Agent: fix-login",
+            "head_branch": "agent/fix-login",
+            "conclusion": "failure"
+        }
+    });
+    let (event_type, project, message) = event_to_activity("workflow_run", &payload).unwrap();
+    assert_eq!(event_type, ActivityEventType::Error);
+    assert_eq!(project, "sentra");
+    assert!(message.contains("Agent: fix-login"));
+}
+
+#[test]
+fn merged_and_unmerged_pr_closes_are_distinguished() {
+    let mut payload = serde_json::json!({
+        "action": "closed",
+        "repository": { "name": "sentra" },
+        "pull_request": { "number": 7, "title": "Add blame view", "merged": true }
+    });
+    let (event_type, _, message) = event_to_activity("pull_request", &payload).unwrap();
+    assert_eq!(event_type, ActivityEventType::PrMerged);
+    assert!(message.contains("#7"));
+
+    payload["pull_request"]["merged"] = serde_json::json!(false);
+    assert!(event_to_activity("pull_request", &payload).is_none());
+}
+
+#[test]
+fn untracked_events_are_ignored() {
+    let payload = serde_json::json!({ "action": "created", "repository": { "name": "sentra" } });
+    assert!(event_to_activity("star", &payload).is_none());
+    assert!(event_to_activity("issues", &payload).is_none());
+}